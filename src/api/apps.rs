@@ -0,0 +1,195 @@
+use crate::api::build::BUILD_STREAM_ACCEPT;
+use crate::api::client::NepheliosClient;
+use anyhow::{bail, Result};
+use reqwest::header::ACCEPT;
+use reqwest::{Response, StatusCode};
+use serde::Deserialize;
+use serde_json::json;
+
+/// Runtime status of an application, as reported by the server.
+#[derive(Debug, Clone, Deserialize)]
+pub struct AppStatus {
+    /// Number of replicas the service should run.
+    pub replicas: u32,
+
+    /// Number of tasks currently running.
+    #[serde(default)]
+    pub running: u32,
+
+    /// Autoscaling bounds, when autoscaling is enabled.
+    #[serde(default)]
+    pub min_replicas: Option<u32>,
+
+    #[serde(default)]
+    pub max_replicas: Option<u32>,
+
+    /// Version of the service spec, increased by every update of the service.
+    #[serde(default)]
+    pub version: Option<u64>,
+
+    /// Number of running tasks that use the current version of the service spec,
+    /// if the server reports it.
+    #[serde(default)]
+    pub updated: Option<u32>,
+
+    /// State of the last rolling update of the service, if it was ever updated.
+    #[serde(default)]
+    pub update_state: Option<UpdateState>,
+
+    /// Explanation given by the swarm for the update state (e.g., why it was paused).
+    #[serde(default)]
+    pub update_message: Option<String>,
+}
+
+/// State of a rolling update, as reported by Docker swarm.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum UpdateState {
+    Updating,
+    Paused,
+    Completed,
+    RollbackStarted,
+    RollbackPaused,
+    RollbackCompleted,
+    #[serde(other)]
+    Unknown,
+}
+
+impl AppStatus {
+    /// Whether every requested replica is running.
+    pub fn is_converged(&self) -> bool {
+        self.running >= self.replicas
+    }
+
+    /// Number of replicas known to run the current version of the service spec.
+    pub fn updated_replicas(&self) -> u32 {
+        self.updated.unwrap_or(self.running)
+    }
+
+    /// Whether the service was updated since `since` and every replica now
+    /// runs the new version of its spec.
+    ///
+    /// Servers that do not report the updated replicas are trusted once the
+    /// update is completed and every replica is running.
+    ///
+    /// # Arguments
+    ///
+    /// * `since` - The spec version before the update was requested, if known
+    pub fn is_rolled_out(&self, since: Option<u64>) -> bool {
+        let updated_spec = match (since, self.version) {
+            (Some(since), Some(version)) => version > since,
+            _ => true,
+        };
+        updated_spec
+            && matches!(self.update_state, None | Some(UpdateState::Completed))
+            && self
+                .updated
+                .map_or(self.is_converged(), |updated| updated >= self.replicas)
+    }
+
+    /// Whether the swarm stopped the rolling update instead of completing it.
+    pub fn is_update_aborted(&self) -> bool {
+        matches!(
+            self.update_state,
+            Some(
+                UpdateState::Paused
+                    | UpdateState::RollbackStarted
+                    | UpdateState::RollbackPaused
+                    | UpdateState::RollbackCompleted
+            )
+        )
+    }
+}
+
+impl NepheliosClient {
+    /// Restarts the replicas of an application one at a time.
+    pub async fn restart_app(&self, app_name: &str) -> Result<()> {
+        let response = self
+            .post("/restart")
+            .json(&json!({ "app_name": app_name, "rolling": true }))
+            .send()
+            .await?;
+        Self::check(response, &format!("restart {}", app_name)).await?;
+        Ok(())
+    }
+
+    /// Fetches the runtime status of an application.
+    pub async fn app_status(&self, app_name: &str) -> Result<AppStatus> {
+        let response = self
+            .get(&format!("/apps/{}/status", app_name))
+            .send()
+            .await?;
+        let response = Self::check(response, &format!("fetch status of {}", app_name)).await?;
+        Ok(response.json().await?)
+    }
+
+    /// Changes the number of replicas of an application.
+    ///
+    /// # Arguments
+    ///
+    /// * `app_name` - The name of the application
+    /// * `replicas` - The number of replicas to run, if fixed
+    /// * `min_replicas` - The lower autoscaling bound, if any
+    /// * `max_replicas` - The upper autoscaling bound, if any
+    pub async fn scale_app(
+        &self,
+        app_name: &str,
+        replicas: Option<u32>,
+        min_replicas: Option<u32>,
+        max_replicas: Option<u32>,
+    ) -> Result<()> {
+        let mut payload = json!({ "app_name": app_name });
+        if let Some(replicas) = replicas {
+            payload["replicas"] = json!(replicas);
+        }
+        if let Some(min) = min_replicas {
+            payload["min_replicas"] = json!(min);
+        }
+        if let Some(max) = max_replicas {
+            payload["max_replicas"] = json!(max);
+        }
+
+        let response = self.post("/scale").json(&payload).send().await?;
+
+        let autoscaling = min_replicas.is_some() || max_replicas.is_some();
+        if autoscaling
+            && matches!(
+                response.status(),
+                StatusCode::NOT_IMPLEMENTED | StatusCode::UNPROCESSABLE_ENTITY
+            )
+        {
+            bail!("The server does not support autoscaling (--min/--max)");
+        }
+
+        Self::check(response, &format!("scale {}", app_name)).await?;
+        Ok(())
+    }
+
+    /// Rebuilds an application from its stored repository and updates its service.
+    ///
+    /// The new tasks are started before the old ones are stopped, so the
+    /// application stays available. The build output is streamed in the response.
+    ///
+    /// # Arguments
+    ///
+    /// * `app_name` - The name of the application
+    /// * `git_ref` - The branch, tag or commit to build, defaulting to the stored one
+    pub async fn redeploy(&self, app_name: &str, git_ref: Option<&str>) -> Result<Response> {
+        let mut payload = json!({
+            "app_name": app_name,
+            "update_order": "start-first",
+        });
+        if let Some(git_ref) = git_ref {
+            payload["git_ref"] = json!(git_ref);
+        }
+
+        let response = self
+            .post("/redeploy")
+            .header(ACCEPT, BUILD_STREAM_ACCEPT)
+            .long_running()
+            .json(&payload)
+            .send()
+            .await?;
+        Self::check(response, &format!("redeploy {}", app_name)).await
+    }
+}
@@ -0,0 +1,56 @@
+use crate::api::client::NepheliosClient;
+use anyhow::Result;
+use serde::Deserialize;
+use serde_json::json;
+
+/// Token issued by the server in exchange for valid credentials.
+#[derive(Debug, Deserialize)]
+pub struct LoginResponse {
+    pub token: String,
+
+    #[serde(default)]
+    pub username: Option<String>,
+}
+
+/// The user an API token belongs to.
+#[derive(Debug, Deserialize)]
+pub struct User {
+    pub username: String,
+
+    #[serde(default)]
+    pub email: Option<String>,
+
+    #[serde(default)]
+    pub role: Option<String>,
+
+    /// `invited` until the user accepts their invitation.
+    #[serde(default)]
+    pub status: Option<String>,
+}
+
+impl NepheliosClient {
+    /// Exchanges a username and password for an API token.
+    pub async fn login(&self, username: &str, password: &str) -> Result<LoginResponse> {
+        let response = self
+            .post("/auth/login")
+            .json(&json!({ "username": username, "password": password }))
+            .send()
+            .await?;
+        let response = Self::check(response, &format!("log in as {}", username)).await?;
+        Ok(response.json().await?)
+    }
+
+    /// Revokes the API token the client is authenticated with.
+    pub async fn logout(&self) -> Result<()> {
+        let response = self.post("/auth/logout").send().await?;
+        Self::check(response, "log out").await?;
+        Ok(())
+    }
+
+    /// Fetches the user the client is authenticated as.
+    pub async fn whoami(&self) -> Result<User> {
+        let response = self.get("/auth/whoami").send().await?;
+        let response = Self::check(response, "fetch the current user").await?;
+        Ok(response.json().await?)
+    }
+}
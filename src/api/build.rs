@@ -0,0 +1,123 @@
+use anyhow::Result;
+use futures_util::stream::StreamExt;
+use reqwest::Response;
+use serde::Deserialize;
+
+/// Media types accepted for streamed build output, in order of preference.
+pub const BUILD_STREAM_ACCEPT: &str = "application/x-ndjson, text/event-stream";
+
+/// An event emitted by the server while building and deploying an application.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum BuildEvent {
+    /// A new build step started (e.g., "Cloning repository").
+    Step { name: String },
+
+    /// A line of build output.
+    Log { line: String },
+
+    /// The build and deployment succeeded.
+    Done {
+        #[serde(default)]
+        message: Option<String>,
+    },
+
+    /// The build or deployment failed.
+    Error { message: String },
+}
+
+/// Parses one line of a build stream.
+///
+/// Both newline-delimited JSON and server-sent events are accepted. Lines
+/// that are not JSON events are treated as plain log output.
+///
+/// # Returns
+///
+/// * `Some(BuildEvent)` for an event or a log line
+/// * `None` for blank lines and SSE metadata
+pub fn parse_build_line(line: &str) -> Option<BuildEvent> {
+    let line = line.trim_end_matches('\r');
+    if line.trim().is_empty() || line.starts_with(':') {
+        return None;
+    }
+    if ["event:", "id:", "retry:"]
+        .iter()
+        .any(|field| line.starts_with(field))
+    {
+        return None;
+    }
+
+    let data = line
+        .strip_prefix("data:")
+        .map(|data| data.trim_start())
+        .unwrap_or(line);
+
+    Some(
+        serde_json::from_str(data).unwrap_or_else(|_| BuildEvent::Log {
+            line: data.to_string(),
+        }),
+    )
+}
+
+/// Reads the build events streamed in a response body as they arrive.
+///
+/// A response that is not streamed (a plain JSON document) produces a single
+/// `Done` event carrying its `message` field, if any.
+///
+/// # Arguments
+///
+/// * `response` - A successful response from a build endpoint
+/// * `on_event` - Called for each event, in order
+pub async fn follow_build<F>(response: Response, mut on_event: F) -> Result<()>
+where
+    F: FnMut(BuildEvent),
+{
+    let streamed = response
+        .headers()
+        .get(reqwest::header::CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .is_some_and(|value| {
+            value.starts_with("application/x-ndjson") || value.starts_with("text/event-stream")
+        });
+
+    if !streamed {
+        let body = response.text().await?;
+        on_event(BuildEvent::Done {
+            message: parse_done_message(&body),
+        });
+        return Ok(());
+    }
+
+    let mut stream = response.bytes_stream();
+    let mut buffer: Vec<u8> = Vec::new();
+
+    while let Some(chunk) = stream.next().await {
+        buffer.extend_from_slice(&chunk?);
+
+        while let Some(end) = buffer.iter().position(|byte| *byte == b'\n') {
+            let line: Vec<u8> = buffer.drain(..=end).collect();
+            if let Some(event) = parse_build_line(&String::from_utf8_lossy(&line[..end])) {
+                on_event(event);
+            }
+        }
+    }
+
+    if let Some(event) = parse_build_line(&String::from_utf8_lossy(&buffer)) {
+        on_event(event);
+    }
+
+    Ok(())
+}
+
+/// Returns the `message` field of the JSON body of a build that was not streamed.
+///
+/// # Arguments
+///
+/// * `body` - The response body
+pub fn parse_done_message(body: &str) -> Option<String> {
+    serde_json::from_str::<serde_json::Value>(body)
+        .ok()?
+        .get("message")?
+        .as_str()
+        .map(str::to_string)
+}
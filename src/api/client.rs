@@ -0,0 +1,725 @@
+use crate::api::oauth::OAuthProvider;
+use crate::api::retry::{is_retryable_error, is_retryable_status, retry_after, RetryPolicy};
+use crate::api::transport::{Failure, Transport, TransportError};
+use crate::api::version::{ServerVersion, API_VERSION, API_VERSION_HEADER};
+use crate::types::app_spec::AppSpec;
+use crate::utils::config::{Config, ContextConfig};
+use crate::utils::credentials::{load_session, store_session, Session};
+use crate::utils::logging::redact_body;
+use crate::utils::server_url::{endpoint_url, parse_server_url, parse_unix_socket, UNIX_BASE_URL};
+use crate::utils::spinner::{report_above_spinner, report_under_spinner};
+use crate::utils::version_cache::VersionCache;
+use anyhow::{anyhow, bail, Context as _, Result};
+use reqwest::header::{HeaderName, HeaderValue, AUTHORIZATION};
+use reqwest::{
+    Certificate, Client, Identity, NoProxy, Proxy, Request, RequestBuilder, Response, StatusCode,
+};
+use serde::{Deserialize, Serialize};
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+use std::time::{Instant, SystemTime};
+use tracing::{debug, info, trace, Level};
+use url::Url;
+use uuid::Uuid;
+
+/// Header carrying the ID of a request, to match it with the server logs.
+pub const REQUEST_ID_HEADER: &str = "x-request-id";
+
+/// HTTP client for the Nephelios server API.
+///
+/// The server location is read from `NEPHELIOS_URL` and `NEPHELIOS_PORT`,
+/// either from the environment or from a `.env` file (see [`parse_server_url`]).
+/// A `unix:///path/to/nephelios.sock` URL reaches a local server over a Unix
+/// domain socket instead of TCP. The API token in `NEPHELIOS_TOKEN`, or else
+/// the one the active context is logged in with, is sent as a bearer token with
+/// every request. Sessions obtained through SSO are refreshed once when the
+/// server rejects them as expired.
+pub struct NepheliosClient {
+    http: Client,
+    transport: Transport,
+    base_url: Url,
+
+    /// The server as configured by the user, for messages.
+    address: String,
+    session: Mutex<Option<Session>>,
+
+    /// Name of the context the session belongs to, when it can be refreshed.
+    context: Option<String>,
+    settings: ContextConfig,
+
+    /// Whether the compatibility of the server must be checked before the next request.
+    check_version: AtomicBool,
+}
+
+/// A request to the Nephelios API, built by [`NepheliosClient::get`],
+/// [`NepheliosClient::post`] or [`NepheliosClient::delete`].
+pub struct ApiRequest<'a> {
+    client: &'a NepheliosClient,
+    builder: RequestBuilder,
+
+    /// Whether the request may be retried after a failure the server may have seen.
+    idempotent: bool,
+
+    /// Whether the request lasts as long as the server works (e.g., a build), without timeout.
+    long_running: bool,
+}
+
+impl ApiRequest<'_> {
+    pub fn header(mut self, name: HeaderName, value: &'static str) -> Self {
+        self.builder = self.builder.header(name, value);
+        self
+    }
+
+    pub fn json<T: Serialize + ?Sized>(mut self, json: &T) -> Self {
+        self.builder = self.builder.json(json);
+        self
+    }
+
+    /// Marks the request as safe to send more than once, so that it is retried
+    /// on transient failures like a GET request.
+    pub fn idempotent(mut self) -> Self {
+        self.idempotent = true;
+        self
+    }
+
+    /// Lifts the request timeout, for requests that last as long as the server
+    /// works, such as builds.
+    pub fn long_running(mut self) -> Self {
+        self.long_running = true;
+        self
+    }
+
+    /// Sends the request, retrying transient failures as set by the [`RetryPolicy`],
+    /// and refreshing the session and retrying once when the server answers
+    /// 401 Unauthorized.
+    pub async fn send(self) -> Result<Response> {
+        self.client
+            .execute(self.builder, self.idempotent, self.long_running)
+            .await
+    }
+}
+
+impl NepheliosClient {
+    /// Creates a client for the server configured in the environment,
+    /// authenticated with `NEPHELIOS_TOKEN` or as the active context.
+    pub fn from_env() -> Result<Self> {
+        let config = Config::load()?;
+        let context = config.active_name(Config::selected());
+        let mut client = Self::with_config(&config, None)?;
+
+        if let Some(token) = env_token() {
+            client.session = Mutex::new(Some(Session {
+                token,
+                refresh_token: None,
+            }));
+            return Ok(client);
+        }
+
+        client.session = Mutex::new(load_session(&context)?);
+        client.context = Some(context);
+        Ok(client)
+    }
+
+    /// Creates a client for the server configured in the environment, using the
+    /// TLS and proxy settings of the active context. It checks the compatibility
+    /// of the server before its first request.
+    ///
+    /// # Arguments
+    ///
+    /// * `token` - The API token to send as a bearer token, if any
+    pub fn new(token: Option<&str>) -> Result<Self> {
+        Self::with_config(&Config::load()?, token)
+    }
+
+    /// Creates a client for the server configured in the environment, using the
+    /// settings of the active context of an already loaded configuration.
+    fn with_config(config: &Config, token: Option<&str>) -> Result<Self> {
+        dotenv::dotenv().ok();
+
+        let url = env::var("NEPHELIOS_URL").ok();
+        let settings = config.active(Config::selected());
+
+        let client = match parse_unix_socket(url.as_deref())? {
+            Some(socket) => Self::for_socket(socket, settings, token)?,
+            None => {
+                let base_url =
+                    parse_server_url(url.as_deref(), env::var("NEPHELIOS_PORT").ok().as_deref())?;
+                Self::for_server(base_url, settings, token)?
+            }
+        };
+        client.check_version.store(true, Ordering::Relaxed);
+        Ok(client)
+    }
+
+    /// Creates a client for the given server.
+    ///
+    /// # Arguments
+    ///
+    /// * `base_url` - The base URL of the API, as returned by [`parse_server_url`]
+    /// * `settings` - The settings of the context, for TLS, proxy and SSO
+    /// * `token` - The API token to send as a bearer token, if any
+    pub fn for_server(base_url: Url, settings: ContextConfig, token: Option<&str>) -> Result<Self> {
+        let http = build_http(&settings)?;
+        Ok(Self {
+            transport: Transport::Tcp(http.clone()),
+            http,
+            address: base_url.to_string(),
+            base_url,
+            session: Mutex::new(token.map(|token| Session {
+                token: token.to_string(),
+                refresh_token: None,
+            })),
+            context: None,
+            settings,
+            check_version: AtomicBool::new(false),
+        })
+    }
+
+    /// Creates a client for a server listening on a Unix domain socket.
+    ///
+    /// # Arguments
+    ///
+    /// * `socket` - The path of the socket
+    /// * `settings` - The settings of the context, for SSO
+    /// * `token` - The API token to send as a bearer token, if any
+    pub fn for_socket(
+        socket: PathBuf,
+        settings: ContextConfig,
+        token: Option<&str>,
+    ) -> Result<Self> {
+        let mut client = Self::for_server(Url::parse(UNIX_BASE_URL)?, settings, token)?;
+        client.address = format!("unix://{}", socket.display());
+        client.transport = Transport::unix(socket)?;
+        Ok(client)
+    }
+
+    /// Returns the address of the server: its base URL, or its Unix socket.
+    pub fn base_url(&self) -> &str {
+        &self.address
+    }
+
+    /// Returns the underlying HTTP client, for requests to other services such
+    /// as the identity provider. It never sends the API token on its own.
+    pub fn http(&self) -> &Client {
+        &self.http
+    }
+
+    /// Builds the full URL of an API endpoint, below the path prefix of the server URL.
+    pub fn url(&self, path: &str) -> Url {
+        endpoint_url(&self.base_url, path)
+    }
+
+    pub fn get(&self, path: &str) -> ApiRequest<'_> {
+        self.request(self.http.get(self.url(path)), true)
+    }
+
+    pub fn post(&self, path: &str) -> ApiRequest<'_> {
+        self.request(
+            self.http
+                .post(self.url(path))
+                .header("Content-Type", "application/json"),
+            false,
+        )
+    }
+
+    pub fn delete(&self, path: &str) -> ApiRequest<'_> {
+        self.request(
+            self.http
+                .delete(self.url(path))
+                .header("Content-Type", "application/json"),
+            false,
+        )
+    }
+
+    fn request(&self, builder: RequestBuilder, idempotent: bool) -> ApiRequest<'_> {
+        ApiRequest {
+            client: self,
+            builder,
+            idempotent,
+            long_running: false,
+        }
+    }
+
+    async fn execute(
+        &self,
+        builder: RequestBuilder,
+        idempotent: bool,
+        long_running: bool,
+    ) -> Result<Response> {
+        if self.check_version.swap(false, Ordering::Relaxed) {
+            self.check_compatibility().await;
+        }
+
+        let mut request = self.prepare(builder, long_running)?;
+        if let Some(session) = self.session.lock().unwrap().as_ref() {
+            request
+                .headers_mut()
+                .insert(AUTHORIZATION, bearer(&session.token)?);
+        }
+
+        let retry = request.try_clone();
+        let response = self.execute_with_retries(request, idempotent).await?;
+        if response.status() != StatusCode::UNAUTHORIZED {
+            return Ok(response);
+        }
+
+        let Some(mut retry) = retry else {
+            return Ok(response);
+        };
+        let Some(token) = self.refresh_session().await? else {
+            return Ok(response);
+        };
+        retry.headers_mut().insert(AUTHORIZATION, bearer(&token)?);
+        self.execute_with_retries(retry, idempotent).await
+    }
+
+    /// Builds a request, announcing the API version of the CLI, tagging it with a
+    /// new request ID and applying the timeout.
+    fn prepare(&self, builder: RequestBuilder, long_running: bool) -> Result<Request> {
+        let mut request = builder.build()?;
+        request.headers_mut().insert(
+            API_VERSION_HEADER,
+            HeaderValue::from_str(&API_VERSION.to_string())?,
+        );
+        request.headers_mut().insert(
+            REQUEST_ID_HEADER,
+            HeaderValue::from_str(&Uuid::new_v4().to_string())?,
+        );
+        if !long_running {
+            *request.timeout_mut() = Some(RetryPolicy::current().timeout);
+        }
+        Ok(request)
+    }
+
+    /// Sends an idempotent request without credentials, bypassing the
+    /// compatibility check, for the requests of the check itself.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The endpoint to request
+    /// * `retry` - Whether to retry the request while it fails transiently
+    pub async fn send_anonymous(&self, path: &str, retry: bool) -> Result<Response> {
+        let request = self.prepare(self.http.get(self.url(path)), false)?;
+        if retry {
+            return self.execute_with_retries(request, true).await;
+        }
+
+        let id = header_request_id(&request);
+        self.send_logged(request, &id)
+            .await
+            .map_err(|e| with_request_id(e, &id))
+    }
+
+    /// Sends a request, and sends it again after a delay while it fails transiently.
+    ///
+    /// Connection failures and responses that may succeed later are retried
+    /// with a jittered exponential backoff, or after the delay requested by the
+    /// server in `Retry-After`. Each retry is reported under the active spinner.
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - The request to send
+    /// * `idempotent` - Whether the request may be retried after a failure the server may have seen
+    async fn execute_with_retries(&self, request: Request, idempotent: bool) -> Result<Response> {
+        let policy = RetryPolicy::current();
+        let id = header_request_id(&request);
+        let mut request = request;
+        let mut attempt = 0;
+
+        loop {
+            let next = request.try_clone().filter(|_| attempt < policy.retries);
+            let result = self.send_logged(request, &id).await;
+
+            let Some(next) = next else {
+                return result.map_err(|e| with_request_id(e, &id));
+            };
+            let (delay, reason) = match &result {
+                Ok(response) if is_retryable_status(response.status(), idempotent) => (
+                    retry_after(response).unwrap_or_else(|| policy.backoff(attempt)),
+                    response.status().to_string(),
+                ),
+                Err(e) if is_retryable_error(e.failure, idempotent) => {
+                    (policy.backoff(attempt), e.reason().to_string())
+                }
+                _ => return result.map_err(|e| with_request_id(e, &id)),
+            };
+
+            attempt += 1;
+            info!(request_id = %id, "retry {}/{} in {:?} ({})", attempt, policy.retries, delay, reason);
+            report_under_spinner(&format!(
+                "{} ({}), retry {}/{} in {:.1}s",
+                next.url().path(),
+                reason,
+                attempt,
+                policy.retries,
+                delay.as_secs_f32()
+            ));
+            tokio::time::sleep(delay).await;
+            request = next;
+        }
+    }
+
+    /// Sends a request once, logging it according to the verbosity.
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - The request to send
+    /// * `id` - The ID of the request, recorded in the response for error messages
+    async fn send_logged(&self, request: Request, id: &str) -> Result<Response, TransportError> {
+        let method = request.method().clone();
+        let url = request.url().clone();
+        // Requests without timeout may stream their response for minutes, so
+        // their body is never buffered for the logs.
+        let buffered = request.timeout().is_some();
+
+        debug!(request_id = %id, "{} {} request headers: {:?}", method, url, request.headers());
+        if let Some(body) = request.body().and_then(|body| body.as_bytes()) {
+            trace!(request_id = %id, "request body: {}", redact_body(body));
+        }
+
+        let started = Instant::now();
+        let result = self.transport.execute(request).await;
+        let elapsed = started.elapsed().as_millis();
+
+        let mut response = match result {
+            Ok(response) => response,
+            Err(e) => {
+                info!(request_id = %id, "{} {} failed after {} ms: {}", method, url, elapsed, e);
+                return Err(e);
+            }
+        };
+        info!(
+            request_id = %id,
+            "{} {} {} in {} ms",
+            method,
+            url,
+            response.status(),
+            elapsed
+        );
+        debug!(request_id = %id, "response headers: {:?}", response.headers());
+        if buffered && tracing::enabled!(Level::TRACE) {
+            response = log_response_body(response, id).await?;
+        }
+
+        response.extensions_mut().insert(RequestId(id.to_string()));
+        Ok(response)
+    }
+
+    /// Warns when the server speaks another version of the API than this CLI.
+    ///
+    /// The version of the server is cached for a while, so that it is not
+    /// fetched before every command. A server that cannot be reached is not
+    /// reported here, nor retried, as the request that triggered the check
+    /// will fail too, after its own retries.
+    async fn check_compatibility(&self) {
+        let cached = VersionCache::load().get(&self.address, SystemTime::now());
+        let server = match cached {
+            Some(server) => server,
+            None => match self.probe_server_version().await {
+                Ok(server) => server,
+                Err(_) => return,
+            },
+        };
+
+        if let Some(mismatch) = server.as_ref().and_then(ServerVersion::mismatch) {
+            report_above_spinner(&format!("⚠️  {}", mismatch));
+        }
+    }
+
+    /// Records that the compatibility of the server is being checked, so that
+    /// the next request does not check it again.
+    pub fn mark_version_checked(&self) {
+        self.check_version.store(false, Ordering::Relaxed);
+    }
+
+    /// Caches the version reported by the server, ignoring failures to write the cache.
+    pub fn cache_server_version(&self, server: Option<&ServerVersion>) {
+        let mut cache = VersionCache::load();
+        cache.insert(&self.address, server, SystemTime::now());
+        cache.save().ok();
+    }
+
+    /// Obtains a new API token with the refresh token of the session, and stores it.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Some(token))` with the new API token
+    /// * `Ok(None)` if the session was not obtained through SSO and cannot be refreshed
+    /// * `Err(anyhow::Error)` if the identity provider refused to refresh the session
+    async fn refresh_session(&self) -> Result<Option<String>> {
+        let Some(context) = &self.context else {
+            return Ok(None);
+        };
+        let (Some(issuer), Some(client_id)) =
+            (&self.settings.oidc_issuer, &self.settings.oidc_client_id)
+        else {
+            return Ok(None);
+        };
+        let refresh_token = self
+            .session
+            .lock()
+            .unwrap()
+            .as_ref()
+            .and_then(|session| session.refresh_token.clone());
+        let Some(refresh_token) = refresh_token else {
+            return Ok(None);
+        };
+
+        let provider = OAuthProvider::discover(self.http.clone(), issuer, client_id).await?;
+        let tokens = provider
+            .refresh(&refresh_token)
+            .await
+            .map_err(|e| anyhow!("{}. Run `nephelios-cli login --device` again.", e))?;
+
+        let session = Session {
+            token: tokens.access_token,
+            refresh_token: tokens.refresh_token.or(Some(refresh_token)),
+        };
+        store_session(context, &session)?;
+        let token = session.token.clone();
+        *self.session.lock().unwrap() = Some(session);
+        Ok(Some(token))
+    }
+
+    /// Turns an unsuccessful response into an error carrying the server message.
+    ///
+    /// # Arguments
+    ///
+    /// * `response` - The response to check
+    /// * `action` - What the request was doing, used in the error (e.g., "start my-app")
+    pub async fn check(response: Response, action: &str) -> Result<Response> {
+        if response.status().is_success() {
+            return Ok(response);
+        }
+
+        let status = response.status();
+        let id = request_id(&response).map(str::to_string);
+        let error_text = response.text().await.unwrap_or_default();
+        let message = if status == StatusCode::FORBIDDEN {
+            permission_denied(action, &error_text)
+        } else if status == StatusCode::UNAUTHORIZED {
+            format!(
+                "Failed to {}: not logged in, or the session expired. Run `nephelios-cli login` first.\nError: {}",
+                action, error_text
+            )
+        } else {
+            format!(
+                "Failed to {}. Status: {}\nError: {}",
+                action, status, error_text
+            )
+        };
+        match id {
+            Some(id) => bail!("{}\nRequest ID: {}", message, id),
+            None => bail!("{}", message),
+        }
+    }
+
+    /// Checks whether the server answers HTTP requests.
+    pub async fn is_reachable(&self) -> bool {
+        self.get("/").send().await.is_ok()
+    }
+
+    /// Fetches the application types supported by the server.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Some(types))` if the server advertises its supported types
+    /// * `Ok(None)` if the server does not expose this information
+    /// * `Err(anyhow::Error)` if the server could not be reached
+    pub async fn app_types(&self) -> Result<Option<Vec<String>>> {
+        let response = self.get("/app-types").send().await?;
+
+        if !response.status().is_success() {
+            return Ok(None);
+        }
+
+        Ok(response.json().await.ok())
+    }
+
+    /// Fetches the live state of an application.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Some(AppSpec))` if the application exists
+    /// * `Ok(None)` if no application has this name
+    /// * `Err(anyhow::Error)` if the request failed
+    pub async fn get_app(&self, app_name: &str) -> Result<Option<AppSpec>> {
+        let response = self.get(&format!("/apps/{}", app_name)).send().await?;
+
+        if response.status() == StatusCode::NOT_FOUND {
+            return Ok(None);
+        }
+
+        let response = Self::check(response, &format!("fetch application {}", app_name)).await?;
+        Ok(Some(response.json().await?))
+    }
+}
+
+/// Builds the HTTP client shared by all requests, configured with the TLS and
+/// proxy settings of a context.
+///
+/// Without a proxy in the context, the standard `HTTPS_PROXY`, `HTTP_PROXY`
+/// and `NO_PROXY` environment variables apply.
+///
+/// # Arguments
+///
+/// * `settings` - The settings of the context
+///
+/// # Returns
+///
+/// * `Ok(Client)` if the certificates could be loaded and the proxy is valid
+/// * `Err(anyhow::Error)` if a certificate or key is missing or invalid, or the proxy URL is invalid
+fn build_http(settings: &ContextConfig) -> Result<Client> {
+    let tls = &settings.tls;
+    let mut builder = Client::builder();
+
+    if let Some(proxy) = &settings.proxy {
+        let no_proxy = settings.no_proxy.as_deref().and_then(NoProxy::from_string);
+        let proxy = Proxy::all(proxy)
+            .with_context(|| format!("Invalid proxy URL '{}'", proxy))?
+            .no_proxy(no_proxy);
+        builder = builder.proxy(proxy);
+    }
+
+    if let Some(path) = &tls.ca_cert {
+        let pem = read_pem(path)?;
+        let certificates = Certificate::from_pem_bundle(&pem)
+            .with_context(|| format!("Invalid CA certificate in {}", path.display()))?;
+        if certificates.is_empty() {
+            bail!("No CA certificate found in {}", path.display());
+        }
+        for certificate in certificates {
+            builder = builder.add_root_certificate(certificate);
+        }
+    }
+
+    match (&tls.client_cert, &tls.client_key) {
+        (Some(cert), Some(key)) => {
+            let identity = Identity::from_pkcs8_pem(&read_pem(cert)?, &read_pem(key)?)
+                .with_context(|| {
+                    format!(
+                        "Invalid client certificate {} or key {} (the key must be PKCS#8)",
+                        cert.display(),
+                        key.display()
+                    )
+                })?;
+            builder = builder.identity(identity);
+        }
+        (None, None) => {}
+        _ => bail!("A client certificate and its key must be given together"),
+    }
+
+    if tls.skips_verification() {
+        eprintln!("⚠️  TLS certificate verification is disabled. Do not use this in production.");
+        builder = builder.danger_accept_invalid_certs(true);
+    }
+
+    Ok(builder.build()?)
+}
+
+fn read_pem(path: &Path) -> Result<Vec<u8>> {
+    fs::read(path).with_context(|| format!("Failed to read {}", path.display()))
+}
+
+/// ID a request was sent with, recorded in the extensions of its response.
+#[derive(Debug, Clone)]
+struct RequestId(String);
+
+/// Returns the ID of the request a response answers, to quote in error messages.
+///
+/// The ID is sent in the `X-Request-Id` header, so that the server logs the
+/// request under the same ID.
+pub fn request_id(response: &Response) -> Option<&str> {
+    response
+        .extensions()
+        .get::<RequestId>()
+        .map(|id| id.0.as_str())
+}
+
+fn header_request_id(request: &Request) -> String {
+    request
+        .headers()
+        .get(REQUEST_ID_HEADER)
+        .and_then(|id| id.to_str().ok())
+        .unwrap_or_default()
+        .to_string()
+}
+
+/// Adds the ID of a request that could not be completed to its error.
+fn with_request_id(error: TransportError, id: &str) -> anyhow::Error {
+    anyhow::Error::new(error.with_request_id(id))
+}
+
+/// Logs the body of a response, and returns an identical response to read it again.
+async fn log_response_body(response: Response, id: &str) -> Result<Response, TransportError> {
+    let mut builder = http::Response::builder().status(response.status());
+    for (name, value) in response.headers() {
+        builder = builder.header(name, value);
+    }
+    let body = response
+        .bytes()
+        .await
+        .map_err(|e| TransportError::new(Failure::Lost, e))?;
+    trace!(request_id = %id, "response body: {}", redact_body(&body));
+
+    builder
+        .body(body)
+        .map(Response::from)
+        .map_err(|e| TransportError::new(Failure::Other, e))
+}
+
+/// Builds the value of an `Authorization` header, hidden from debug output.
+fn bearer(token: &str) -> Result<HeaderValue> {
+    let mut value = HeaderValue::from_str(&format!("Bearer {}", token))?;
+    value.set_sensitive(true);
+    Ok(value)
+}
+
+/// Returns the API token set in `NEPHELIOS_TOKEN`, if any.
+pub fn env_token() -> Option<String> {
+    dotenv::dotenv().ok();
+    env::var("NEPHELIOS_TOKEN")
+        .ok()
+        .filter(|token| !token.trim().is_empty())
+}
+
+/// Body of a 403 Forbidden response, naming the role the request needed.
+#[derive(Debug, Default, Deserialize)]
+struct Forbidden {
+    #[serde(default)]
+    required_role: Option<String>,
+
+    /// The application the role is needed on, when it is not a platform role.
+    #[serde(default)]
+    app_name: Option<String>,
+}
+
+/// Explains a 403 Forbidden response, naming the missing role when the server reports it.
+///
+/// # Arguments
+///
+/// * `action` - What the request was doing (e.g., "remove my-app")
+/// * `body` - The body of the response
+pub fn permission_denied(action: &str, body: &str) -> String {
+    let forbidden: Forbidden = serde_json::from_str(body).unwrap_or_default();
+
+    match (forbidden.required_role, forbidden.app_name) {
+        (Some(role), Some(app_name)) => format!(
+            "Failed to {}: permission denied. This requires the '{}' role on {}. \
+             Ask an administrator to run `nephelios-cli users grant <username> --name {} --role {}`.",
+            action, role, app_name, app_name, role
+        ),
+        (Some(role), None) => format!(
+            "Failed to {}: permission denied. This requires the '{}' role. \
+             Ask an administrator to run `nephelios-cli users set-role <username> {}`.",
+            action, role, role
+        ),
+        _ => format!(
+            "Failed to {}: permission denied.\nError: {}",
+            action, body
+        ),
+    }
+}
@@ -0,0 +1,147 @@
+use crate::api::client::NepheliosClient;
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+/// ACME directory of Let's Encrypt, used when none is configured.
+pub const LETS_ENCRYPT_DIRECTORY: &str = "https://acme-v02.api.letsencrypt.org/directory";
+
+/// A host name routed to an application.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Domain {
+    pub host: String,
+
+    /// The URL the application is reachable at through this host.
+    pub url: String,
+
+    /// The TLS certificate served for this host, if any.
+    #[serde(default)]
+    pub certificate: Option<Certificate>,
+}
+
+/// A TLS certificate served for a domain.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Certificate {
+    /// One of `pending`, `issued`, `failed` or `expired`.
+    pub status: String,
+
+    /// `acme` for certificates requested and renewed by the server, `uploaded` otherwise.
+    #[serde(default)]
+    pub source: String,
+
+    #[serde(default)]
+    pub expires_at: Option<String>,
+
+    /// Why the last issuance or renewal failed.
+    #[serde(default)]
+    pub error: Option<String>,
+}
+
+impl Certificate {
+    /// Describes the certificate on one line (e.g., "issued by ACME, expires 2025-01-01").
+    pub fn summary(&self) -> String {
+        let mut summary = self.status.clone();
+        if self.source == "acme" {
+            summary.push_str(" by ACME");
+        } else if !self.source.is_empty() {
+            summary.push_str(&format!(" ({})", self.source));
+        }
+        if let Some(expires_at) = &self.expires_at {
+            summary.push_str(&format!(", expires {}", expires_at));
+        }
+        if let Some(error) = &self.error {
+            summary.push_str(&format!(": {}", error));
+        }
+        summary
+    }
+}
+
+/// ACME account used by the server to request and renew certificates.
+#[derive(Debug, Clone, Serialize)]
+pub struct AcmeSettings {
+    /// URL of the ACME directory (e.g., Let's Encrypt or a local Pebble).
+    pub directory: String,
+
+    /// Contact address for expiry notices.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+}
+
+impl NepheliosClient {
+    /// Lists the domains of an application, as routed by the server.
+    pub async fn domains(&self, app_name: &str) -> Result<Vec<Domain>> {
+        let response = self
+            .get(&format!("/apps/{}/domains", app_name))
+            .send()
+            .await?;
+        let response = Self::check(response, &format!("list domains of {}", app_name)).await?;
+        Ok(response.json().await?)
+    }
+
+    /// Routes a host name to an application.
+    ///
+    /// # Arguments
+    ///
+    /// * `app_name` - The name of the application
+    /// * `host` - The fully qualified host name
+    /// * `acme` - The ACME account to request a certificate with, or `None` to serve plain HTTP
+    pub async fn add_domain(
+        &self,
+        app_name: &str,
+        host: &str,
+        acme: Option<&AcmeSettings>,
+    ) -> Result<Domain> {
+        let mut payload = json!({ "host": host });
+        if let Some(acme) = acme {
+            payload["tls"] = json!({ "acme": acme });
+        }
+
+        let response = self
+            .post(&format!("/apps/{}/domains", app_name))
+            .json(&payload)
+            .send()
+            .await?;
+        let response =
+            Self::check(response, &format!("add domain {} to {}", host, app_name)).await?;
+        Ok(response.json().await?)
+    }
+
+    /// Stops routing a host name to an application.
+    pub async fn remove_domain(&self, app_name: &str, host: &str) -> Result<()> {
+        let response = self
+            .delete(&format!("/apps/{}/domains/{}", app_name, host))
+            .send()
+            .await?;
+        Self::check(
+            response,
+            &format!("remove domain {} from {}", host, app_name),
+        )
+        .await?;
+        Ok(())
+    }
+
+    /// Serves a certificate provided by the user for a domain.
+    ///
+    /// # Arguments
+    ///
+    /// * `app_name` - The name of the application
+    /// * `host` - The fully qualified host name
+    /// * `certificate` - The PEM certificate chain
+    /// * `private_key` - The PEM private key
+    pub async fn upload_certificate(
+        &self,
+        app_name: &str,
+        host: &str,
+        certificate: &str,
+        private_key: &str,
+    ) -> Result<Certificate> {
+        let response = self
+            .post(&format!("/apps/{}/domains/{}/certificate", app_name, host))
+            .json(&json!({ "certificate": certificate, "private_key": private_key }))
+            .send()
+            .await?;
+        let response =
+            Self::check(response, &format!("upload the certificate of {}", host)).await?;
+        Ok(response.json().await?)
+    }
+}
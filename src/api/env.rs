@@ -0,0 +1,47 @@
+use crate::api::client::NepheliosClient;
+use anyhow::Result;
+use serde_json::json;
+use std::collections::BTreeMap;
+
+impl NepheliosClient {
+    /// Fetches the environment variables of an application.
+    pub async fn get_env(&self, app_name: &str) -> Result<BTreeMap<String, String>> {
+        let response = self.get(&format!("/apps/{}/env", app_name)).send().await?;
+        let response = Self::check(
+            response,
+            &format!("list environment variables of {}", app_name),
+        )
+        .await?;
+        Ok(response.json().await?)
+    }
+
+    /// Sets environment variables of an application, keeping the other ones.
+    pub async fn set_env(&self, app_name: &str, vars: &BTreeMap<String, String>) -> Result<()> {
+        let response = self
+            .post(&format!("/apps/{}/env", app_name))
+            .json(&json!({ "env": vars }))
+            .send()
+            .await?;
+        Self::check(
+            response,
+            &format!("set environment variables of {}", app_name),
+        )
+        .await?;
+        Ok(())
+    }
+
+    /// Removes environment variables from an application.
+    pub async fn unset_env(&self, app_name: &str, keys: &[String]) -> Result<()> {
+        let response = self
+            .delete(&format!("/apps/{}/env", app_name))
+            .json(&json!({ "keys": keys }))
+            .send()
+            .await?;
+        Self::check(
+            response,
+            &format!("unset environment variables of {}", app_name),
+        )
+        .await?;
+        Ok(())
+    }
+}
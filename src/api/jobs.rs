@@ -0,0 +1,99 @@
+use crate::api::client::NepheliosClient;
+use anyhow::{anyhow, Result};
+use reqwest::Response;
+use serde::Deserialize;
+
+/// Response header carrying the ID of the job started by a request.
+pub const JOB_ID_HEADER: &str = "X-Nephelios-Job-Id";
+
+/// A long-running operation executed by the server, such as a build.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Job {
+    pub id: String,
+
+    /// What the job does (e.g., create, redeploy).
+    #[serde(default)]
+    pub kind: String,
+
+    #[serde(default)]
+    pub app_name: String,
+
+    /// One of `queued`, `running`, `succeeded`, `failed` or `cancelled`.
+    pub status: String,
+
+    /// Completion percentage, when known.
+    #[serde(default)]
+    pub progress: Option<u8>,
+
+    /// Current step (e.g., "Building image").
+    #[serde(default)]
+    pub step: Option<String>,
+
+    #[serde(default)]
+    pub error: Option<String>,
+
+    #[serde(default)]
+    pub created_at: String,
+}
+
+impl Job {
+    /// Whether the job has stopped, successfully or not.
+    pub fn is_finished(&self) -> bool {
+        matches!(self.status.as_str(), "succeeded" | "failed" | "cancelled")
+    }
+
+    pub fn is_succeeded(&self) -> bool {
+        self.status == "succeeded"
+    }
+}
+
+/// Returns the ID of the job started by a request, as reported in its headers.
+pub fn job_id_header(response: &Response) -> Option<String> {
+    response
+        .headers()
+        .get(JOB_ID_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .map(str::to_string)
+}
+
+/// Reads the ID of the job queued by a detached request.
+///
+/// The ID is taken from the response headers, or from the `job_id` field of
+/// the response body.
+pub async fn accepted_job_id(response: Response) -> Result<String> {
+    if let Some(job_id) = job_id_header(&response) {
+        return Ok(job_id);
+    }
+
+    let body: serde_json::Value = response.json().await?;
+    body["job_id"]
+        .as_str()
+        .map(str::to_string)
+        .ok_or_else(|| anyhow!("The server did not return a job ID"))
+}
+
+impl NepheliosClient {
+    /// Lists the jobs known to the server, newest first.
+    pub async fn jobs(&self) -> Result<Vec<Job>> {
+        let response = self.get("/jobs").send().await?;
+        let response = Self::check(response, "list jobs").await?;
+        Ok(response.json().await?)
+    }
+
+    /// Fetches the current state of a job.
+    pub async fn job(&self, job_id: &str) -> Result<Job> {
+        let response = self.get(&format!("/jobs/{}", job_id)).send().await?;
+        let response = Self::check(response, &format!("fetch job {}", job_id)).await?;
+        Ok(response.json().await?)
+    }
+
+    /// Asks the server to stop a job.
+    pub async fn cancel_job(&self, job_id: &str) -> Result<()> {
+        let response = self
+            .post(&format!("/jobs/{}/cancel", job_id))
+            .send()
+            .await?;
+        Self::check(response, &format!("cancel job {}", job_id)).await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,15 @@
+pub mod apps;
+pub mod auth;
+pub mod build;
+pub mod client;
+pub mod domains;
+pub mod env;
+pub mod jobs;
+pub mod oauth;
+pub mod releases;
+pub mod retry;
+pub mod secrets;
+pub mod tokens;
+pub mod transport;
+pub mod users;
+pub mod version;
@@ -0,0 +1,211 @@
+use anyhow::{anyhow, bail, Result};
+use reqwest::Client;
+use serde::Deserialize;
+use std::time::{Duration, Instant};
+
+/// Grant type of the OAuth 2.0 device authorization grant (RFC 8628).
+const DEVICE_CODE_GRANT: &str = "urn:ietf:params:oauth:grant-type:device_code";
+
+/// Scopes requested at login, including a refresh token.
+const SCOPES: &str = "openid profile offline_access";
+
+/// Polling interval used when the identity provider does not set one.
+const DEFAULT_INTERVAL: u64 = 5;
+
+/// Endpoints advertised by an OpenID Connect identity provider.
+#[derive(Debug, Deserialize)]
+struct Discovery {
+    device_authorization_endpoint: Option<String>,
+    token_endpoint: String,
+}
+
+/// Codes issued by the identity provider when a device login starts.
+#[derive(Debug, Clone, Deserialize)]
+pub struct DeviceAuthorization {
+    pub device_code: String,
+
+    /// The code the user enters on the verification page.
+    pub user_code: String,
+
+    pub verification_uri: String,
+
+    /// The verification page with the user code already filled in.
+    #[serde(default)]
+    pub verification_uri_complete: Option<String>,
+
+    /// How long the codes are valid, in seconds.
+    pub expires_in: u64,
+
+    /// How long to wait between two token requests, in seconds.
+    #[serde(default)]
+    pub interval: Option<u64>,
+}
+
+/// Tokens issued by the identity provider.
+#[derive(Debug, Clone, Deserialize)]
+pub struct TokenResponse {
+    pub access_token: String,
+
+    #[serde(default)]
+    pub refresh_token: Option<String>,
+}
+
+/// Error returned by the token endpoint (RFC 6749, section 5.2).
+#[derive(Debug, Deserialize)]
+struct TokenError {
+    error: String,
+
+    #[serde(default)]
+    error_description: Option<String>,
+}
+
+/// An OAuth 2.0 identity provider, located through OpenID Connect discovery.
+pub struct OAuthProvider {
+    http: Client,
+    client_id: String,
+    device_authorization_endpoint: Option<String>,
+    token_endpoint: String,
+}
+
+impl OAuthProvider {
+    /// Fetches the endpoints of an identity provider from its discovery document.
+    ///
+    /// # Arguments
+    ///
+    /// * `http` - The HTTP client used to reach the identity provider
+    /// * `issuer` - The issuer URL (e.g., https://sso.example.com/realms/nephelios)
+    /// * `client_id` - The OAuth client registered for the CLI
+    pub async fn discover(http: Client, issuer: &str, client_id: &str) -> Result<Self> {
+        let url = format!(
+            "{}/.well-known/openid-configuration",
+            issuer.trim_end_matches('/')
+        );
+        let response = http.get(&url).send().await?;
+        if !response.status().is_success() {
+            bail!(
+                "Failed to discover the identity provider at {}. Status: {}",
+                url,
+                response.status()
+            );
+        }
+        let discovery: Discovery = response.json().await?;
+
+        Ok(Self {
+            http,
+            client_id: client_id.to_string(),
+            device_authorization_endpoint: discovery.device_authorization_endpoint,
+            token_endpoint: discovery.token_endpoint,
+        })
+    }
+
+    /// Starts a device login, returning the codes to show to the user.
+    pub async fn authorize_device(&self) -> Result<DeviceAuthorization> {
+        let Some(endpoint) = &self.device_authorization_endpoint else {
+            bail!("The identity provider does not support the device authorization grant");
+        };
+
+        let response = self
+            .http
+            .post(endpoint)
+            .form(&[("client_id", self.client_id.as_str()), ("scope", SCOPES)])
+            .send()
+            .await?;
+        if !response.status().is_success() {
+            let status = response.status();
+            let error_text = response.text().await.unwrap_or_default();
+            bail!(
+                "Failed to start the device login. Status: {}\nError: {}",
+                status,
+                error_text
+            );
+        }
+
+        Ok(response.json().await?)
+    }
+
+    /// Polls the token endpoint until the user approves or denies the device login.
+    ///
+    /// # Arguments
+    ///
+    /// * `device` - The codes returned by [`OAuthProvider::authorize_device`]
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(TokenResponse)` once the user approved the login
+    /// * `Err(anyhow::Error)` if the user denied it, or the codes expired
+    pub async fn wait_for_token(&self, device: &DeviceAuthorization) -> Result<TokenResponse> {
+        let expires = Instant::now() + Duration::from_secs(device.expires_in);
+        let mut interval = Duration::from_secs(device.interval.unwrap_or(DEFAULT_INTERVAL));
+
+        loop {
+            if Instant::now() >= expires {
+                bail!("The device code expired before the login was approved");
+            }
+            tokio::time::sleep(interval).await;
+
+            let result = self
+                .request_token(&[
+                    ("grant_type", DEVICE_CODE_GRANT),
+                    ("device_code", &device.device_code),
+                    ("client_id", &self.client_id),
+                ])
+                .await?;
+
+            match result {
+                Ok(token) => return Ok(token),
+                Err(error) => match error.error.as_str() {
+                    "authorization_pending" => {}
+                    "slow_down" => interval += Duration::from_secs(5),
+                    "access_denied" => bail!("The login was denied"),
+                    "expired_token" => {
+                        bail!("The device code expired before the login was approved")
+                    }
+                    _ => bail!(
+                        "The device login failed: {}",
+                        error.error_description.unwrap_or(error.error)
+                    ),
+                },
+            }
+        }
+    }
+
+    /// Exchanges a refresh token for a new access token.
+    pub async fn refresh(&self, refresh_token: &str) -> Result<TokenResponse> {
+        self.request_token(&[
+            ("grant_type", "refresh_token"),
+            ("refresh_token", refresh_token),
+            ("client_id", &self.client_id),
+        ])
+        .await?
+        .map_err(|error| {
+            anyhow!(
+                "Failed to refresh the session: {}",
+                error.error_description.unwrap_or(error.error)
+            )
+        })
+    }
+
+    /// Sends a token request, separating OAuth errors from transport failures.
+    async fn request_token(
+        &self,
+        form: &[(&str, &str)],
+    ) -> Result<Result<TokenResponse, TokenError>> {
+        let response = self
+            .http
+            .post(&self.token_endpoint)
+            .form(form)
+            .send()
+            .await?;
+
+        if response.status().is_success() {
+            return Ok(Ok(response.json().await?));
+        }
+
+        let status = response.status();
+        let body = response.text().await.unwrap_or_default();
+        match serde_json::from_str::<TokenError>(&body) {
+            Ok(error) => Ok(Err(error)),
+            Err(_) => bail!("Token request failed. Status: {}\nError: {}", status, body),
+        }
+    }
+}
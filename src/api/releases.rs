@@ -0,0 +1,71 @@
+use crate::api::client::NepheliosClient;
+use anyhow::Result;
+use serde::Deserialize;
+use serde_json::json;
+
+/// A deployed build of an application.
+///
+/// Each release points to an image stored in the platform registry, so any
+/// previous release can be deployed again.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Release {
+    pub number: u32,
+
+    #[serde(default)]
+    pub commit: String,
+
+    #[serde(default)]
+    pub created_at: String,
+
+    #[serde(default)]
+    pub image_digest: String,
+
+    /// `active` for the running release, `superseded`, `failed` or `rolled_back` otherwise.
+    #[serde(default)]
+    pub status: String,
+}
+
+impl Release {
+    pub fn is_active(&self) -> bool {
+        self.status == "active"
+    }
+
+    pub fn is_failed(&self) -> bool {
+        self.status == "failed"
+    }
+
+    /// Returns the abbreviated commit hash.
+    pub fn short_commit(&self) -> &str {
+        self.commit.get(..7).unwrap_or(&self.commit)
+    }
+}
+
+impl NepheliosClient {
+    /// Lists the releases of an application, newest first.
+    pub async fn releases(&self, app_name: &str) -> Result<Vec<Release>> {
+        let response = self
+            .get(&format!("/apps/{}/releases", app_name))
+            .send()
+            .await?;
+        let response = Self::check(response, &format!("list releases of {}", app_name)).await?;
+
+        let mut releases: Vec<Release> = response.json().await?;
+        releases.sort_by_key(|release| std::cmp::Reverse(release.number));
+        Ok(releases)
+    }
+
+    /// Redeploys the image of a previous release.
+    pub async fn rollback(&self, app_name: &str, release: u32) -> Result<()> {
+        let response = self
+            .post("/rollback")
+            .json(&json!({ "app_name": app_name, "release": release }))
+            .send()
+            .await?;
+        Self::check(
+            response,
+            &format!("roll back {} to release {}", app_name, release),
+        )
+        .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,144 @@
+use crate::api::transport::Failure;
+use rand::Rng;
+use reqwest::header::RETRY_AFTER;
+use reqwest::{Response, StatusCode};
+use std::sync::OnceLock;
+use std::time::{Duration, SystemTime};
+
+/// Number of times a failed request is retried by default.
+pub const DEFAULT_RETRIES: u32 = 3;
+
+/// Time a request may take by default, streamed builds excepted.
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Delay before the first retry, doubled on every further attempt.
+const BASE_DELAY: Duration = Duration::from_millis(500);
+
+/// Upper bound of the backoff delay.
+const MAX_DELAY: Duration = Duration::from_secs(10);
+
+/// Upper bound of the delay requested by the server with `Retry-After`.
+const MAX_RETRY_AFTER: Duration = Duration::from_secs(60);
+
+/// Policy selected on the command line for the whole run.
+static POLICY: OnceLock<RetryPolicy> = OnceLock::new();
+
+/// How requests to the Nephelios API are retried when they fail transiently.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RetryPolicy {
+    /// Number of retries after the first attempt.
+    pub retries: u32,
+
+    /// Time a single attempt may take.
+    pub timeout: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            retries: DEFAULT_RETRIES,
+            timeout: DEFAULT_TIMEOUT,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Records the policy selected on the command line, used by every API client.
+    pub fn install(self) {
+        POLICY.set(self).ok();
+    }
+
+    /// Returns the policy recorded with [`RetryPolicy::install`], or the default one.
+    pub fn current() -> Self {
+        POLICY.get().copied().unwrap_or_default()
+    }
+
+    /// Returns how long to wait before the given retry.
+    ///
+    /// The delay grows exponentially and is drawn at random from its upper half,
+    /// so that clients failing together do not retry together.
+    ///
+    /// # Arguments
+    ///
+    /// * `attempt` - The number of the retry, starting at 0
+    pub fn backoff(&self, attempt: u32) -> Duration {
+        let delay = BASE_DELAY
+            .saturating_mul(2u32.saturating_pow(attempt))
+            .min(MAX_DELAY);
+        delay.mul_f64(rand::thread_rng().gen_range(0.5..=1.0))
+    }
+}
+
+/// Whether a response status means the request may succeed if sent again.
+///
+/// 429 Too Many Requests means that the server did not handle the request at
+/// all, so it is safe to retry whatever the request.
+///
+/// # Arguments
+///
+/// * `status` - The status of the response
+/// * `idempotent` - Whether sending the request twice has the same effect as sending it once
+pub fn is_retryable_status(status: StatusCode, idempotent: bool) -> bool {
+    match status {
+        StatusCode::TOO_MANY_REQUESTS => true,
+        StatusCode::BAD_GATEWAY | StatusCode::SERVICE_UNAVAILABLE | StatusCode::GATEWAY_TIMEOUT => {
+            idempotent
+        }
+        _ => false,
+    }
+}
+
+/// Whether a failed attempt may succeed if sent again.
+///
+/// A refused connection never reached the server, so it is safe to retry
+/// whatever the request. Other failures, such as a reset connection or a
+/// timeout, may happen after the server handled the request.
+///
+/// # Arguments
+///
+/// * `failure` - Why the attempt failed
+/// * `idempotent` - Whether sending the request twice has the same effect as sending it once
+pub fn is_retryable_error(failure: Failure, idempotent: bool) -> bool {
+    match failure {
+        Failure::Connect => true,
+        Failure::Timeout | Failure::Lost => idempotent,
+        Failure::Other => false,
+    }
+}
+
+/// Returns the delay requested by a 429 or 503 response in its `Retry-After` header.
+///
+/// The header holds either a number of seconds or an HTTP date. The delay is
+/// capped, so that a misconfigured server cannot block the CLI for hours.
+///
+/// # Arguments
+///
+/// * `response` - The response to inspect
+pub fn retry_after(response: &Response) -> Option<Duration> {
+    if !matches!(
+        response.status(),
+        StatusCode::TOO_MANY_REQUESTS | StatusCode::SERVICE_UNAVAILABLE
+    ) {
+        return None;
+    }
+    let value = response.headers().get(RETRY_AFTER)?.to_str().ok()?;
+    parse_retry_after(value, SystemTime::now())
+}
+
+/// Parses the value of a `Retry-After` header.
+///
+/// # Arguments
+///
+/// * `value` - A number of seconds or an HTTP date
+/// * `now` - The current time, to turn a date into a delay
+pub fn parse_retry_after(value: &str, now: SystemTime) -> Option<Duration> {
+    let value = value.trim();
+    let delay = match value.parse::<u64>() {
+        Ok(seconds) => Duration::from_secs(seconds),
+        Err(_) => httpdate::parse_http_date(value)
+            .ok()?
+            .duration_since(now)
+            .unwrap_or_default(),
+    };
+    Some(delay.min(MAX_RETRY_AFTER))
+}
@@ -0,0 +1,128 @@
+use crate::api::client::NepheliosClient;
+use anyhow::{bail, Result};
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use serde::Deserialize;
+use serde_json::json;
+
+/// A secret of an application, without its value.
+#[derive(Debug, Deserialize)]
+pub struct SecretInfo {
+    pub name: String,
+
+    #[serde(default)]
+    pub created_at: Option<String>,
+
+    #[serde(default)]
+    pub attached: bool,
+}
+
+/// Response of the server to an attach request.
+#[derive(Debug, Deserialize)]
+struct AttachResponse {
+    /// `false` when the secret was already attached.
+    #[serde(default)]
+    attached: Option<bool>,
+}
+
+/// Checks that a secret name can be used as a file name under `/run/secrets`.
+///
+/// Names are made of letters, digits, `_`, `-` and `.`, and may not start with a dot.
+pub fn validate_secret_name(name: &str) -> Result<()> {
+    let valid = !name.is_empty()
+        && name.len() <= 64
+        && !name.starts_with('.')
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '.'));
+    if !valid {
+        bail!(
+            "Invalid secret name '{}'. Use letters, digits, '_', '-' and '.' (e.g., DB_PASSWORD)",
+            name
+        );
+    }
+    Ok(())
+}
+
+impl NepheliosClient {
+    /// Stores a new secret of an application on the platform.
+    ///
+    /// The server keeps it as a swarm secret; its value can never be read back.
+    ///
+    /// # Arguments
+    ///
+    /// * `app_name` - The name of the application
+    /// * `name` - The name of the secret, unique within the application
+    /// * `value` - The secret value
+    pub async fn create_secret(&self, app_name: &str, name: &str, value: &[u8]) -> Result<()> {
+        let response = self
+            .post(&format!("/apps/{}/secrets", app_name))
+            .json(&json!({ "name": name, "secret_value": STANDARD.encode(value) }))
+            .send()
+            .await?;
+        Self::check(response, &format!("create secret {} of {}", name, app_name)).await?;
+        Ok(())
+    }
+
+    /// Lists the secrets of an application.
+    pub async fn list_secrets(&self, app_name: &str) -> Result<Vec<SecretInfo>> {
+        let response = self
+            .get(&format!("/apps/{}/secrets", app_name))
+            .send()
+            .await?;
+        let response = Self::check(response, &format!("list secrets of {}", app_name)).await?;
+
+        let mut secrets: Vec<SecretInfo> = response.json().await?;
+        secrets.sort_by(|a, b| a.name.cmp(&b.name));
+        Ok(secrets)
+    }
+
+    /// Mounts a secret in the application's service, which restarts its tasks.
+    ///
+    /// # Arguments
+    ///
+    /// * `app_name` - The name of the application
+    /// * `name` - The name of the secret
+    /// * `target` - The file name under `/run/secrets`, defaulting to the secret name
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(true)` if the secret was attached
+    /// * `Ok(false)` if the secret was already attached
+    pub async fn attach_secret(
+        &self,
+        app_name: &str,
+        name: &str,
+        target: Option<&str>,
+    ) -> Result<bool> {
+        let response = self
+            .post(&format!("/apps/{}/secrets/{}/attach", app_name, name))
+            .json(&json!({ "target": target.unwrap_or(name) }))
+            .send()
+            .await?;
+        let response =
+            Self::check(response, &format!("attach secret {} to {}", name, app_name)).await?;
+
+        let attached = response
+            .json::<AttachResponse>()
+            .await
+            .ok()
+            .and_then(|response| response.attached);
+        Ok(attached.unwrap_or(true))
+    }
+
+    /// Removes a secret, after detaching it from the application's service.
+    ///
+    /// # Arguments
+    ///
+    /// * `app_name` - The name of the application
+    /// * `name` - The name of the secret
+    pub async fn remove_secret(&self, app_name: &str, name: &str) -> Result<()> {
+        let response = self
+            .delete(&format!("/apps/{}/secrets/{}", app_name, name))
+            .send()
+            .await?;
+        Self::check(response, &format!("remove secret {} of {}", name, app_name)).await?;
+        Ok(())
+    }
+}
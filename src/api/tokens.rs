@@ -0,0 +1,79 @@
+use crate::api::client::NepheliosClient;
+use anyhow::Result;
+use serde::Deserialize;
+use serde_json::json;
+use std::time::Duration;
+
+/// A long-lived API token, without its secret.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ApiToken {
+    pub id: String,
+
+    #[serde(default)]
+    pub name: Option<String>,
+
+    #[serde(default)]
+    pub scopes: Vec<String>,
+
+    #[serde(default)]
+    pub created_at: String,
+
+    /// `None` for tokens that never expire.
+    #[serde(default)]
+    pub expires_at: Option<String>,
+
+    #[serde(default)]
+    pub last_used_at: Option<String>,
+}
+
+/// A token that was just created, with its secret.
+#[derive(Debug, Deserialize)]
+pub struct CreatedToken {
+    #[serde(flatten)]
+    pub token: ApiToken,
+
+    /// The value to send as a bearer token. The server only returns it once.
+    pub secret: String,
+}
+
+impl NepheliosClient {
+    /// Creates an API token for the current user.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - A description of what the token is used for
+    /// * `scopes` - What the token is allowed to do (e.g., deploy)
+    /// * `expires_in` - How long the token is valid, or `None` for the server default
+    pub async fn create_token(
+        &self,
+        name: Option<&str>,
+        scopes: &[String],
+        expires_in: Option<Duration>,
+    ) -> Result<CreatedToken> {
+        let mut payload = json!({ "scopes": scopes });
+        if let Some(name) = name {
+            payload["name"] = json!(name);
+        }
+        if let Some(expires_in) = expires_in {
+            payload["expires_in"] = json!(expires_in.as_secs());
+        }
+
+        let response = self.post("/tokens").json(&payload).send().await?;
+        let response = Self::check(response, "create the token").await?;
+        Ok(response.json().await?)
+    }
+
+    /// Lists the API tokens of the current user.
+    pub async fn tokens(&self) -> Result<Vec<ApiToken>> {
+        let response = self.get("/tokens").send().await?;
+        let response = Self::check(response, "list tokens").await?;
+        Ok(response.json().await?)
+    }
+
+    /// Revokes an API token, so that it is rejected from now on.
+    pub async fn revoke_token(&self, id: &str) -> Result<()> {
+        let response = self.delete(&format!("/tokens/{}", id)).send().await?;
+        Self::check(response, &format!("revoke token {}", id)).await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,209 @@
+use anyhow::anyhow;
+use reqwest::{Client, Request, Response};
+use std::fmt;
+use std::path::PathBuf;
+
+/// How requests reach the Nephelios server.
+pub enum Transport {
+    /// HTTP(S) over TCP, through a proxy when one is configured.
+    Tcp(Client),
+
+    /// Plain HTTP over a Unix domain socket, for a server running on the same machine.
+    #[cfg(unix)]
+    Unix(Box<unix::UnixTransport>),
+}
+
+/// Why a request could not be completed.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Failure {
+    /// The connection could not be opened, so the server never saw the request.
+    Connect,
+
+    /// The server did not answer in time.
+    Timeout,
+
+    /// The connection broke while the request or the response was sent.
+    Lost,
+
+    /// The request itself is invalid.
+    Other,
+}
+
+/// A request that could not be completed.
+#[derive(Debug)]
+pub struct TransportError {
+    pub failure: Failure,
+    error: anyhow::Error,
+    request_id: Option<String>,
+}
+
+impl TransportError {
+    pub fn new(failure: Failure, error: impl Into<anyhow::Error>) -> Self {
+        Self {
+            failure,
+            error: error.into(),
+            request_id: None,
+        }
+    }
+
+    /// Attaches the ID of the request, shown after the cause like for refused requests.
+    pub fn with_request_id(mut self, id: &str) -> Self {
+        self.request_id = Some(id.to_string());
+        self
+    }
+
+    /// Describes the failure in a few words, for retry reports.
+    pub fn reason(&self) -> &'static str {
+        match self.failure {
+            Failure::Connect => "connection failed",
+            Failure::Timeout => "timed out",
+            Failure::Lost => "connection lost",
+            Failure::Other => "invalid request",
+        }
+    }
+}
+
+impl fmt::Display for TransportError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.error)?;
+        if let Some(id) = &self.request_id {
+            write!(f, "\nRequest ID: {}", id)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for TransportError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        self.error.source()
+    }
+}
+
+impl From<reqwest::Error> for TransportError {
+    fn from(error: reqwest::Error) -> Self {
+        let failure = if error.is_connect() {
+            Failure::Connect
+        } else if error.is_timeout() {
+            Failure::Timeout
+        } else if error.is_request() || error.is_body() {
+            Failure::Lost
+        } else {
+            Failure::Other
+        };
+        Self::new(failure, error)
+    }
+}
+
+impl Transport {
+    /// Sends a request and waits for the response headers.
+    pub async fn execute(&self, request: Request) -> Result<Response, TransportError> {
+        match self {
+            Transport::Tcp(client) => Ok(client.execute(request).await?),
+            #[cfg(unix)]
+            Transport::Unix(transport) => transport.execute(request).await,
+        }
+    }
+
+    /// Creates a transport over the given Unix domain socket.
+    pub fn unix(socket: PathBuf) -> anyhow::Result<Self> {
+        #[cfg(unix)]
+        {
+            Ok(Transport::Unix(Box::new(unix::UnixTransport::new(socket))))
+        }
+        #[cfg(not(unix))]
+        {
+            Err(anyhow!(
+                "Cannot reach {}: Unix sockets are not supported on this platform",
+                socket.display()
+            ))
+        }
+    }
+}
+
+#[cfg(unix)]
+mod unix {
+    use super::{anyhow, Failure, TransportError};
+    use bytes::Bytes;
+    use futures_util::TryStreamExt;
+    use http_body_util::{BodyStream, Full};
+    use hyper_util::client::legacy::Client;
+    use hyperlocal::{UnixClientExt, UnixConnector};
+    use reqwest::{Body, Request, Response};
+    use std::path::PathBuf;
+
+    /// Sends the requests built with `reqwest` through `hyper` over a Unix domain socket,
+    /// and hands the responses back as `reqwest` responses.
+    pub struct UnixTransport {
+        socket: PathBuf,
+        client: Client<UnixConnector, Full<Bytes>>,
+    }
+
+    impl UnixTransport {
+        pub fn new(socket: PathBuf) -> Self {
+            Self {
+                socket,
+                client: Client::unix(),
+            }
+        }
+
+        pub async fn execute(&self, request: Request) -> Result<Response, TransportError> {
+            let url = request.url();
+            let path = match url.query() {
+                Some(query) => format!("{}?{}", url.path(), query),
+                None => url.path().to_string(),
+            };
+
+            let mut builder = hyper::Request::builder()
+                .method(request.method().as_str())
+                .uri(hyperlocal::Uri::new(&self.socket, &path))
+                .header("host", "localhost");
+            for (name, value) in request.headers() {
+                builder = builder.header(name.as_str(), value.as_bytes());
+            }
+            let body = request
+                .body()
+                .and_then(|body| body.as_bytes())
+                .map(Bytes::copy_from_slice)
+                .unwrap_or_default();
+            let hyper_request = builder
+                .body(Full::new(body))
+                .map_err(|e| TransportError::new(Failure::Other, e))?;
+
+            let response = self.client.request(hyper_request);
+            let response = match request.timeout() {
+                Some(timeout) => tokio::time::timeout(*timeout, response)
+                    .await
+                    .map_err(|_| {
+                        TransportError::new(
+                            Failure::Timeout,
+                            anyhow!("No answer from {} in time", self.socket.display()),
+                        )
+                    })?,
+                None => response.await,
+            }
+            .map_err(|e| {
+                let failure = if e.is_connect() {
+                    Failure::Connect
+                } else {
+                    Failure::Lost
+                };
+                TransportError::new(
+                    failure,
+                    anyhow!("Failed to reach {}: {}", self.socket.display(), e),
+                )
+            })?;
+
+            let (parts, body) = response.into_parts();
+            let mut builder = http::Response::builder().status(parts.status.as_u16());
+            for (name, value) in &parts.headers {
+                builder = builder.header(name.as_str(), value.as_bytes());
+            }
+            let body = BodyStream::new(body)
+                .try_filter_map(|frame| async move { Ok(frame.into_data().ok()) });
+            builder
+                .body(Body::wrap_stream(body))
+                .map(Response::from)
+                .map_err(|e| TransportError::new(Failure::Other, e))
+        }
+    }
+}
@@ -0,0 +1,100 @@
+use crate::api::auth::User;
+use crate::api::client::NepheliosClient;
+use crate::types::role::Role;
+use anyhow::Result;
+use serde::Deserialize;
+use serde_json::json;
+
+/// An invitation sent to a new user.
+#[derive(Debug, Deserialize)]
+pub struct Invitation {
+    pub email: String,
+
+    /// Link to accept the invitation, when the server does not email it.
+    #[serde(default)]
+    pub invite_url: Option<String>,
+}
+
+/// A role granted to a user on a single application.
+#[derive(Debug, Deserialize)]
+pub struct Grant {
+    pub username: String,
+    pub role: String,
+}
+
+impl NepheliosClient {
+    /// Lists the users of the platform.
+    pub async fn users(&self) -> Result<Vec<User>> {
+        let response = self.get("/users").send().await?;
+        let response = Self::check(response, "list users").await?;
+        Ok(response.json().await?)
+    }
+
+    /// Invites a new user with a platform role.
+    pub async fn invite_user(&self, email: &str, role: Role) -> Result<Invitation> {
+        let response = self
+            .post("/users/invite")
+            .json(&json!({ "email": email, "role": role }))
+            .send()
+            .await?;
+        let response = Self::check(response, &format!("invite {}", email)).await?;
+        Ok(response.json().await?)
+    }
+
+    /// Removes a user from the platform, revoking their tokens.
+    pub async fn remove_user(&self, username: &str) -> Result<()> {
+        let response = self.delete(&format!("/users/{}", username)).send().await?;
+        Self::check(response, &format!("remove user {}", username)).await?;
+        Ok(())
+    }
+
+    /// Changes the platform role of a user.
+    pub async fn set_user_role(&self, username: &str, role: Role) -> Result<()> {
+        let response = self
+            .post(&format!("/users/{}/role", username))
+            .json(&json!({ "role": role }))
+            .send()
+            .await?;
+        Self::check(response, &format!("set the role of {}", username)).await?;
+        Ok(())
+    }
+
+    /// Lists the roles granted on an application.
+    pub async fn grants(&self, app_name: &str) -> Result<Vec<Grant>> {
+        let response = self
+            .get(&format!("/apps/{}/permissions", app_name))
+            .send()
+            .await?;
+        let response = Self::check(response, &format!("list permissions of {}", app_name)).await?;
+        Ok(response.json().await?)
+    }
+
+    /// Grants a user a role on a single application.
+    pub async fn grant(&self, app_name: &str, username: &str, role: Role) -> Result<()> {
+        let response = self
+            .post(&format!("/apps/{}/permissions", app_name))
+            .json(&json!({ "username": username, "role": role }))
+            .send()
+            .await?;
+        Self::check(
+            response,
+            &format!("grant {} access to {}", username, app_name),
+        )
+        .await?;
+        Ok(())
+    }
+
+    /// Revokes the role of a user on a single application.
+    pub async fn revoke_grant(&self, app_name: &str, username: &str) -> Result<()> {
+        let response = self
+            .delete(&format!("/apps/{}/permissions/{}", app_name, username))
+            .send()
+            .await?;
+        Self::check(
+            response,
+            &format!("revoke the access of {} to {}", username, app_name),
+        )
+        .await?;
+        Ok(())
+    }
+}
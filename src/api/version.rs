@@ -0,0 +1,76 @@
+use crate::api::client::NepheliosClient;
+use anyhow::Result;
+use reqwest::StatusCode;
+use serde::{Deserialize, Serialize};
+
+/// Version of the API this CLI speaks, sent with every request.
+pub const API_VERSION: u32 = 1;
+
+/// Header carrying [`API_VERSION`], so that the server can answer in a compatible way.
+pub const API_VERSION_HEADER: &str = "x-nephelios-api-version";
+
+/// Version reported by the server at `GET /version`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ServerVersion {
+    /// Release of the server (e.g., 0.4.2).
+    pub version: String,
+
+    /// Version of the API the server speaks.
+    pub api_version: u32,
+}
+
+impl ServerVersion {
+    /// Explains how to fix a mismatch between the API of the server and the one
+    /// of the CLI, or returns `None` when they are compatible.
+    pub fn mismatch(&self) -> Option<String> {
+        let cli = env!("CARGO_PKG_VERSION");
+        if self.api_version > API_VERSION {
+            Some(format!(
+                "The Nephelios server {} speaks API version {}, but this CLI ({}) only speaks version {}. \
+                 Update nephelios-cli to a release supporting it.",
+                self.version, self.api_version, cli, API_VERSION
+            ))
+        } else if self.api_version < API_VERSION {
+            Some(format!(
+                "The Nephelios server {} speaks API version {}, but this CLI ({}) expects version {}. \
+                 Update the platform with `nephelios-cli up`, or use an older CLI.",
+                self.version, self.api_version, cli, API_VERSION
+            ))
+        } else {
+            None
+        }
+    }
+}
+
+impl NepheliosClient {
+    /// Fetches the version of the server, and caches it for the compatibility check.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Some(ServerVersion))` if the server reports its version
+    /// * `Ok(None)` if the server predates `GET /version`
+    /// * `Err(anyhow::Error)` if the server could not be reached
+    pub async fn server_version(&self) -> Result<Option<ServerVersion>> {
+        self.fetch_server_version(true).await
+    }
+
+    /// Fetches the version of the server like [`NepheliosClient::server_version`],
+    /// but sends the request only once.
+    pub async fn probe_server_version(&self) -> Result<Option<ServerVersion>> {
+        self.fetch_server_version(false).await
+    }
+
+    async fn fetch_server_version(&self, retry: bool) -> Result<Option<ServerVersion>> {
+        self.mark_version_checked();
+        let response = self.send_anonymous("/version", retry).await?;
+
+        let server = if response.status() == StatusCode::NOT_FOUND {
+            None
+        } else {
+            let response = Self::check(response, "fetch the server version").await?;
+            Some(response.json().await?)
+        };
+        self.cache_server_version(server.as_ref());
+        Ok(server)
+    }
+}
@@ -0,0 +1,95 @@
+use crate::api::client::NepheliosClient;
+use crate::commands::domains::print_access_urls;
+use crate::types::manifest::Manifest;
+use crate::utils::config::ContextConfig;
+use crate::utils::git::{ensure_secure_transport, parse_repo_url, GitCredentials};
+use crate::utils::plan::print_changes;
+use crate::utils::spinner::create_spinner;
+use anyhow::Result;
+use serde_json::json;
+use std::path::Path;
+
+/// Executes the apply command to make an application match its manifest.
+///
+/// The application is created when it does not exist yet. Otherwise only the
+/// fields that differ from the manifest are updated.
+///
+/// # Arguments
+///
+/// * `file` - The path of the manifest (e.g., nephelios.toml)
+/// * `context` - The active context, providing stored Git credentials and the domain suffix
+///
+/// # Returns
+///
+/// * `Ok(())` if the application matches the manifest
+/// * `Err(anyhow::Error)` if the manifest is invalid or the server request failed
+pub async fn execute(file: &Path, context: &ContextConfig) -> Result<()> {
+    let manifest = Manifest::load(file)?;
+    let desired = manifest.to_spec(file)?;
+    let transport = parse_repo_url(&desired.github_url)?;
+
+    let client = NepheliosClient::from_env()?;
+    let spinner = create_spinner(&format!("Fetching {} application...", desired.app_name));
+    let live = client.get_app(&desired.app_name).await;
+    spinner.finish_and_clear();
+
+    let (endpoint, changes) = match live? {
+        None => ("/create", None),
+        Some(live) => {
+            let changes = live.changes(&desired);
+            if changes.is_empty() {
+                println!("✅ Application {:?} is up to date", desired.app_name);
+                return Ok(());
+            }
+            ("/update", Some(changes))
+        }
+    };
+
+    let mut payload = json!(desired);
+    if endpoint == "/create" {
+        let credentials = GitCredentials::resolve(transport, None, None, context)?;
+        if !credentials.is_empty() {
+            ensure_secure_transport(client.base_url())?;
+        }
+        if let Some(token) = &credentials.token {
+            payload["git_token"] = json!(token);
+        }
+        if let Some(ssh_key) = &credentials.ssh_key {
+            payload["git_ssh_key"] = json!(ssh_key);
+        }
+        if let Some(suffix) = &context.domain_suffix {
+            payload["domain_suffix"] = json!(suffix);
+        }
+    }
+
+    let spinner = create_spinner(&format!("Applying {}...", file.display()));
+    let response = client
+        .post(endpoint)
+        .long_running()
+        .json(&payload)
+        .send()
+        .await?;
+    spinner.finish_and_clear();
+
+    NepheliosClient::check(response, &format!("apply {}", file.display())).await?;
+
+    match changes {
+        None => {
+            println!("✅ Application {:?} created:", desired.app_name);
+            println!("   - Application Type: {}", desired.app_type);
+            println!("   - GitHub URL: {}", desired.github_url);
+            println!("   - Replicas: {}", desired.replicas);
+            print_access_urls(&client, &desired.app_name).await;
+        }
+        Some(changes) => {
+            println!(
+                "✅ Application {:?} updated ({} changes):",
+                desired.app_name,
+                changes.len()
+            );
+            print_changes(&changes);
+        }
+    }
+
+    Ok(())
+}
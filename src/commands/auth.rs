@@ -0,0 +1,187 @@
+use crate::api::client::{env_token, NepheliosClient};
+use crate::api::oauth::OAuthProvider;
+use crate::utils::config::Config;
+use crate::utils::credentials::{
+    delete_session, load_session, store_session, Session, SessionStore,
+};
+use crate::utils::git::ensure_secure_transport;
+use crate::utils::spinner::create_spinner;
+use anyhow::{bail, Result};
+use console::style;
+use dialoguer::{Input, Password};
+use std::io::{self, BufRead};
+
+/// OAuth client used by `login --device` when none is configured.
+const DEFAULT_CLIENT_ID: &str = "nephelios-cli";
+
+/// Exchanges credentials for an API token and stores it for the active context.
+///
+/// The token is kept in the system keyring, or in a file only readable by the
+/// current user when no keyring is available.
+///
+/// # Arguments
+///
+/// * `username` - The user to log in as, prompted for when `None`
+/// * `password_stdin` - Whether to read the password from standard input instead of a prompt
+pub async fn login(username: Option<&str>, password_stdin: bool) -> Result<()> {
+    let context = Config::load()?.active_name(Config::selected());
+    let client = NepheliosClient::new(None)?;
+    ensure_secure_transport(client.base_url())?;
+
+    let username = match username {
+        Some(username) => username.to_string(),
+        None => Input::new().with_prompt("Username").interact_text()?,
+    };
+    let password = if password_stdin {
+        let mut password = String::new();
+        io::stdin().lock().read_line(&mut password)?;
+        password.trim_end_matches(['\r', '\n']).to_string()
+    } else {
+        Password::new().with_prompt("Password").interact()?
+    };
+
+    let spinner = create_spinner(&format!("Logging in to {}...", client.base_url()));
+    let result = client.login(&username, &password).await;
+    spinner.finish_and_clear();
+    let response = result?;
+
+    let session = Session {
+        token: response.token,
+        refresh_token: None,
+    };
+    let store = store_session(&context, &session)?;
+    println!(
+        "✅ Logged in as {:?}",
+        response.username.as_deref().unwrap_or(&username)
+    );
+    print_session(&context, store);
+    Ok(())
+}
+
+/// Logs in through the identity provider with the OAuth 2.0 device authorization grant.
+///
+/// The user approves the login in a browser, possibly on another device, while
+/// the CLI polls for the token. The identity provider is remembered in the
+/// context so the session can be refreshed when it expires.
+///
+/// # Arguments
+///
+/// * `issuer` - The issuer URL of the identity provider, overriding the context's
+/// * `client_id` - The OAuth client registered for the CLI, overriding the context's
+pub async fn login_device(issuer: Option<&str>, client_id: Option<&str>) -> Result<()> {
+    let mut config = Config::load()?;
+    let context = config.active_name(Config::selected());
+    let settings = config.contexts.entry(context.clone()).or_default();
+
+    let Some(issuer) = issuer.map(str::to_string).or(settings.oidc_issuer.clone()) else {
+        bail!(
+            "No identity provider configured. Pass --issuer, or set one with \
+             `context set {} --oidc-issuer <url>`",
+            context
+        );
+    };
+    let client_id = client_id
+        .map(str::to_string)
+        .or(settings.oidc_client_id.clone())
+        .unwrap_or_else(|| DEFAULT_CLIENT_ID.to_string());
+    ensure_secure_transport(&issuer)?;
+
+    let client = NepheliosClient::new(None)?;
+    let spinner = create_spinner("Starting the device login...");
+    let result = async {
+        let provider = OAuthProvider::discover(client.http().clone(), &issuer, &client_id).await?;
+        let device = provider.authorize_device().await?;
+        Ok::<_, anyhow::Error>((provider, device))
+    }
+    .await;
+    spinner.finish_and_clear();
+    let (provider, device) = result?;
+
+    println!(
+        "To log in, open {} and enter the code {}",
+        style(&device.verification_uri).underlined(),
+        style(&device.user_code).bold().cyan()
+    );
+    if let Some(uri) = &device.verification_uri_complete {
+        println!("   - Or open: {}", uri);
+    }
+
+    let spinner = create_spinner("Waiting for the login to be approved...");
+    let result = provider.wait_for_token(&device).await;
+    spinner.finish_and_clear();
+    let tokens = result?;
+
+    let session = Session {
+        token: tokens.access_token,
+        refresh_token: tokens.refresh_token,
+    };
+    let store = store_session(&context, &session)?;
+
+    settings.oidc_issuer = Some(issuer);
+    settings.oidc_client_id = Some(client_id);
+    config.save()?;
+
+    println!("✅ Logged in with single sign-on");
+    print_session(&context, store);
+    Ok(())
+}
+
+/// Revokes the API token of the active context and forgets it.
+pub async fn logout() -> Result<()> {
+    let context = Config::load()?.active_name(Config::selected());
+    let Some(session) = load_session(&context)? else {
+        println!("Not logged in to context {:?}", context);
+        return Ok(());
+    };
+
+    // The session is forgotten locally even if the server cannot revoke it
+    let revoked = NepheliosClient::new(Some(&session.token))?.logout().await;
+    delete_session(&context)?;
+
+    println!("✅ Logged out of context {:?}", context);
+    if let Err(e) = revoked {
+        println!("   - The server did not revoke the token: {}", e);
+    }
+    Ok(())
+}
+
+/// Shows the user the active context is logged in as.
+pub async fn whoami() -> Result<()> {
+    let context = Config::load()?.active_name(Config::selected());
+    let from_env = env_token().is_some();
+    if !from_env && load_session(&context)?.is_none() {
+        bail!(
+            "Not logged in to context {:?}. Run `nephelios-cli login` first.",
+            context
+        );
+    }
+
+    let client = NepheliosClient::from_env()?;
+    let spinner = create_spinner("Fetching the current user...");
+    let result = client.whoami().await;
+    spinner.finish_and_clear();
+    let user = result?;
+
+    println!("Logged in as {:?}", user.username);
+    if let Some(email) = &user.email {
+        println!("   - Email: {}", email);
+    }
+    if let Some(role) = &user.role {
+        println!("   - Role: {}", role);
+    }
+    if from_env {
+        println!("   - Token: NEPHELIOS_TOKEN");
+    } else {
+        println!("   - Context: {}", context);
+    }
+    println!("   - Server: {}", client.base_url());
+    Ok(())
+}
+
+fn print_session(context: &str, store: SessionStore) {
+    println!("   - Context: {}", context);
+    match store {
+        SessionStore::Keyring => println!("   - Token stored in: system keyring"),
+        SessionStore::File(path) => println!("   - Token stored in: {}", path.display()),
+    }
+}
@@ -0,0 +1,215 @@
+use crate::utils::config::{Config, ContextConfig, TlsOptions};
+use crate::utils::credentials::{
+    delete_git_token, delete_session, load_git_token, store_git_token, SessionStore,
+};
+use crate::utils::domain::normalize_suffix;
+use anyhow::{bail, Result};
+use std::path::Path;
+
+/// Lists all configured contexts, marking the active one.
+///
+/// # Arguments
+///
+/// * `selected` - The context selected with `--context`, if any
+pub fn list(selected: Option<&str>) -> Result<()> {
+    let config = Config::load()?;
+    let active = config.active_name(selected);
+
+    if config.contexts.is_empty() {
+        println!("No contexts configured, using '{}'", active);
+        return Ok(());
+    }
+
+    for (name, context) in &config.contexts {
+        let marker = if *name == active { "*" } else { " " };
+        println!("{} {}", marker, name);
+        if load_git_token(name)?.is_some() {
+            println!("     - Git token: ********");
+        }
+        if let Some(key) = &context.git_ssh_key {
+            println!("     - Git SSH key: {}", key.display());
+        }
+        if let Some(suffix) = &context.domain_suffix {
+            println!("     - Domain suffix: {}", suffix);
+        }
+        if let Some(directory) = &context.acme_directory {
+            println!("     - ACME directory: {}", directory);
+        }
+        if let Some(email) = &context.acme_email {
+            println!("     - ACME email: {}", email);
+        }
+        if let Some(issuer) = &context.oidc_issuer {
+            println!("     - Identity provider: {}", issuer);
+        }
+        if let Some(proxy) = &context.proxy {
+            println!("     - Proxy: {}", proxy);
+        }
+        if let Some(no_proxy) = &context.no_proxy {
+            println!("     - No proxy: {}", no_proxy);
+        }
+        if let Some(ca_cert) = &context.tls.ca_cert {
+            println!("     - CA certificate: {}", ca_cert.display());
+        }
+        if let Some(client_cert) = &context.tls.client_cert {
+            println!("     - Client certificate: {}", client_cert.display());
+        }
+        if context.tls.skips_verification() {
+            println!("     - TLS verification: ⚠️  disabled");
+        }
+    }
+
+    Ok(())
+}
+
+/// Makes the given context the default one.
+///
+/// # Arguments
+///
+/// * `name` - The name of the context to select
+pub fn use_context(name: &str) -> Result<()> {
+    let mut config = Config::load()?;
+    if !config.contexts.contains_key(name) {
+        bail!(
+            "Context '{}' does not exist. Create it with `context set {}`",
+            name,
+            name
+        );
+    }
+
+    config.current_context = Some(name.to_string());
+    config.save()?;
+    println!("✅ Switched to context {:?}", name);
+    Ok(())
+}
+
+/// Creates a context or updates the given settings of an existing one.
+///
+/// # Arguments
+///
+/// * `name` - The name of the context to create or update
+/// * `git_token` - Access token for private HTTPS repositories
+/// * `git_ssh_key` - Path to a private SSH key for private SSH repositories
+/// * `domain_suffix` - Domain under which applications get their default host name
+/// * `acme_directory` - ACME directory used to request certificates
+/// * `acme_email` - Contact address registered with the ACME directory
+/// * `oidc_issuer` - Issuer URL of the identity provider used by `login --device`
+/// * `oidc_client_id` - OAuth client ID of the CLI at the identity provider
+/// * `proxy` - Proxy used to reach the server
+/// * `no_proxy` - Comma-separated hosts reached without the proxy
+/// * `tls` - TLS options of the connection to the server
+#[allow(clippy::too_many_arguments)]
+pub fn set(
+    name: &str,
+    git_token: Option<&str>,
+    git_ssh_key: Option<&Path>,
+    domain_suffix: Option<&str>,
+    acme_directory: Option<&str>,
+    acme_email: Option<&str>,
+    oidc_issuer: Option<&str>,
+    oidc_client_id: Option<&str>,
+    proxy: Option<&str>,
+    no_proxy: Option<&str>,
+    tls: &TlsOptions,
+) -> Result<()> {
+    let mut config = Config::load()?;
+    let context = config
+        .contexts
+        .entry(name.to_string())
+        .or_insert_with(ContextConfig::default);
+
+    if let Some(key) = git_ssh_key {
+        if !key.is_file() {
+            bail!("SSH key {} does not exist", key.display());
+        }
+        context.git_ssh_key = Some(key.canonicalize()?);
+    }
+    if let Some(suffix) = domain_suffix {
+        context.domain_suffix = Some(normalize_suffix(suffix)?);
+    }
+    if let Some(directory) = acme_directory {
+        if !directory.starts_with("https://") {
+            bail!(
+                "The ACME directory must be an https:// URL, got {}",
+                directory
+            );
+        }
+        context.acme_directory = Some(directory.to_string());
+    }
+    if let Some(email) = acme_email {
+        context.acme_email = Some(email.to_string());
+    }
+    if let Some(issuer) = oidc_issuer {
+        context.oidc_issuer = Some(issuer.trim_end_matches('/').to_string());
+    }
+    if let Some(client_id) = oidc_client_id {
+        context.oidc_client_id = Some(client_id.to_string());
+    }
+    if let Some(proxy) = proxy {
+        if let Err(e) = reqwest::Proxy::all(proxy) {
+            bail!("Invalid proxy URL '{}': {}", proxy, e);
+        }
+        context.proxy = Some(proxy.to_string());
+    }
+    if let Some(no_proxy) = no_proxy {
+        context.no_proxy = Some(no_proxy.to_string());
+    }
+    for path in [&tls.ca_cert, &tls.client_cert, &tls.client_key]
+        .into_iter()
+        .flatten()
+    {
+        if !path.is_file() {
+            bail!("{} does not exist", path.display());
+        }
+    }
+    let tls = TlsOptions {
+        ca_cert: tls.ca_cert.as_deref().map(Path::canonicalize).transpose()?,
+        client_cert: tls
+            .client_cert
+            .as_deref()
+            .map(Path::canonicalize)
+            .transpose()?,
+        client_key: tls
+            .client_key
+            .as_deref()
+            .map(Path::canonicalize)
+            .transpose()?,
+        insecure_skip_verify: tls.insecure_skip_verify,
+    };
+    context.tls = std::mem::take(&mut context.tls).merge(&tls);
+
+    config.save()?;
+    let store = git_token
+        .map(|token| store_git_token(name, token))
+        .transpose()?;
+
+    println!("✅ Context {:?} saved", name);
+    match store {
+        Some(SessionStore::Keyring) => println!("   - Git token stored in: system keyring"),
+        Some(SessionStore::File(path)) => {
+            println!("   - Git token stored in: {}", path.display())
+        }
+        None => {}
+    }
+    Ok(())
+}
+
+/// Deletes a context, along with its session and Git token.
+///
+/// # Arguments
+///
+/// * `name` - The name of the context to delete
+pub fn remove(name: &str) -> Result<()> {
+    let mut config = Config::load()?;
+    if config.contexts.remove(name).is_none() {
+        bail!("Context '{}' does not exist", name);
+    }
+
+    if config.current_context.as_deref() == Some(name) {
+        config.current_context = None;
+    }
+    config.save()?;
+    delete_session(name)?;
+    delete_git_token(name)?;
+    println!("✅ Context {:?} removed", name);
+    Ok(())
+}
@@ -1,16 +1,33 @@
+use crate::api::build::{follow_build, BUILD_STREAM_ACCEPT};
+use crate::api::client::NepheliosClient;
+use crate::api::jobs::{accepted_job_id, job_id_header};
+use crate::commands::{domains, jobs};
+use crate::types::app_type::AppType;
+use crate::utils::build_log::BuildLog;
+use crate::utils::config::ContextConfig;
+use crate::utils::git::{ensure_secure_transport, parse_repo_url, GitCredentials};
 use crate::utils::spinner::create_spinner;
-use anyhow::Result;
-use reqwest::Client;
+use anyhow::{bail, Result};
+use reqwest::header::ACCEPT;
 use serde_json::json;
 use std::env;
+use std::path::Path;
 
 /// Executes the create command to deploy a new application.
 ///
+/// Pressing Ctrl+C while the build is followed offers to leave it running on
+/// the server as a job.
+///
 /// # Arguments
 ///
 /// * `app_name` - The name of the application to deploy
-/// * `app_type` - The type of application (e.g., nodejs, python, rust)
-/// * `github_url` - The GitHub repository URL containing the application code
+/// * `app_type` - The type of application, detected from `path` when `None`
+/// * `path` - The local directory of the application, defaulting to the current directory
+/// * `github_url` - The GitHub repository URL (HTTPS or SSH) containing the application code
+/// * `git_token` - Access token for a private HTTPS repository
+/// * `git_ssh_key` - Path to a private SSH key for a private SSH repository
+/// * `detach` - Whether to return once the deployment is queued instead of following the build
+/// * `context` - The active context, providing stored Git credentials and the domain suffix
 ///
 /// # Returns
 ///
@@ -20,64 +37,152 @@ use std::env;
 /// # Examples
 ///
 /// ```
-/// let result = execute("my-app", "nodejs", "https://github.com/user/repo").await;
+/// let context = ContextConfig::default();
+/// let result = execute(
+///     "my-app",
+///     Some(AppType::Nodejs),
+///     None,
+///     "https://github.com/user/repo",
+///     None,
+///     None,
+///     false,
+///     &context,
+/// )
+/// .await;
 /// match result {
 ///     Ok(_) => println!("Deployment successful"),
 ///     Err(e) => println!("Deployment failed: {}", e),
 /// }
 /// ```
-pub async fn execute(app_name: &str, app_type: &str, github_url: &str) -> Result<()> {
-    let client = Client::new();
-    dotenv::dotenv().ok();
+#[allow(clippy::too_many_arguments)]
+pub async fn execute(
+    app_name: &str,
+    app_type: Option<AppType>,
+    path: Option<&Path>,
+    github_url: &str,
+    git_token: Option<&str>,
+    git_ssh_key: Option<&Path>,
+    detach: bool,
+    context: &ContextConfig,
+) -> Result<()> {
+    let transport = parse_repo_url(github_url)?;
+    let credentials = GitCredentials::resolve(transport, git_token, git_ssh_key, context)?;
+    let app_type = match app_type {
+        Some(app_type) => app_type,
+        None => {
+            let dir = match path {
+                Some(path) => path.to_path_buf(),
+                None => env::current_dir()?,
+            };
+            let detected = AppType::detect(&dir)?;
+            println!("🔍 Detected application type: {}", detected);
+            detected
+        }
+    };
+
+    let client = NepheliosClient::from_env()?;
+
+    if !credentials.is_empty() {
+        ensure_secure_transport(client.base_url())?;
+    }
+    ensure_supported(&client, app_type).await?;
 
-    let nephelios_port: u16 = env::var("NEPHELIOS_PORT")
-        .unwrap_or_else(|_| "3030".to_string())
-        .parse()
-        .unwrap_or(3030);
-    let nephelios_url =
-        env::var("NEPHELIOS_URL").unwrap_or_else(|_| "http://localhost".to_string());
     let spinner = create_spinner(&format!("Deploying {} application...", app_name));
 
-    let payload = json!({
+    let mut payload = json!({
         "app_name": app_name,
-        "app_type": app_type,
+        "app_type": app_type.as_str(),
         "github_url": github_url,
     });
+    if let Some(token) = &credentials.token {
+        payload["git_token"] = json!(token);
+    }
+    if let Some(ssh_key) = &credentials.ssh_key {
+        payload["git_ssh_key"] = json!(ssh_key);
+    }
+    if let Some(suffix) = &context.domain_suffix {
+        payload["domain_suffix"] = json!(suffix);
+    }
+    if detach {
+        payload["detach"] = json!(true);
+    }
 
     let response = client
-        .post(format!("{}:{}/create", nephelios_url, nephelios_port))
-        .header("Content-Type", "application/json")
+        .post("/create")
+        .header(ACCEPT, BUILD_STREAM_ACCEPT)
+        .long_running()
         .json(&payload)
         .send()
-        .await?;
+        .await;
+    let response = match response {
+        Ok(response) => response,
+        Err(e) => {
+            spinner.finish_and_clear();
+            return Err(e);
+        }
+    };
 
-    // Stop the spinner
-    spinner.finish_and_clear();
+    if !response.status().is_success() {
+        spinner.finish_and_clear();
+    }
+    let response = NepheliosClient::check(response, &format!("create {}", app_name)).await?;
+
+    if detach {
+        spinner.finish_and_clear();
+        let job_id = accepted_job_id(response).await?;
+        println!("✅ Deployment queued for {:?}", app_name);
+        println!("   - Job ID: {}", job_id);
+        println!("   - Follow it with: nephelios-cli jobs wait {}", job_id);
+        return Ok(());
+    }
 
-    if response.status().is_success() {
-        // Create the JSON response
-        let response_body = json!({
-            "message": "Application created successfully",
-            "app_name": app_name,
-            "app_type": app_type,
-            "github_url": github_url,
-            "url": format!("https://{}.localhost", app_name),
-        });
+    // Render the build output as it is streamed, until it ends or the user interrupts it
+    let job_id = job_id_header(&response);
+    let mut log = BuildLog::new(app_name, spinner);
+    let streamed = tokio::select! {
+        streamed = follow_build(response, |event| log.handle(event)) => Some(streamed),
+        _ = tokio::signal::ctrl_c() => None,
+    };
+    let Some(streamed) = streamed else {
+        log.detach();
+        return jobs::offer_detach(&client, app_name, job_id.as_deref()).await;
+    };
+    let message = log.finish(streamed)?;
+
+    // Create the JSON response
+    let response_body = json!({
+        "message": message.unwrap_or_else(|| "Application created successfully".to_string()),
+        "app_name": app_name,
+        "app_type": app_type.as_str(),
+        "github_url": github_url,
+    });
+
+    // Improved logging
+    println!("✅ Deployment created successfully:");
+    println!("   - Message: {}", response_body["message"]);
+    println!("   - Application Name: {}", response_body["app_name"]);
+    println!("   - Application Type: {}", response_body["app_type"]);
+    println!("   - GitHub URL: {}", response_body["github_url"]);
+    domains::print_access_urls(&client, app_name).await;
+
+    Ok(())
+}
+
+/// Checks that the server supports the given application type.
+///
+/// Servers that do not advertise their supported types are trusted to
+/// reject unknown types themselves.
+async fn ensure_supported(client: &NepheliosClient, app_type: AppType) -> Result<()> {
+    let Some(supported) = client.app_types().await? else {
+        return Ok(());
+    };
 
-        // Improved logging
-        println!("✅ Deployment created successfully:");
-        println!("   - Message: {}", response_body["message"]);
-        println!("   - Application Name: {}", response_body["app_name"]);
-        println!("   - Application Type: {}", response_body["app_type"]);
-        println!("   - GitHub URL: {}", response_body["github_url"]);
-        println!("   - Access URL: {}", response_body["url"]);
-    } else {
-        println!(
-            "❌ Failed to create deployment. Status: {}",
-            response.status()
+    if !supported.iter().any(|name| name == app_type.as_str()) {
+        bail!(
+            "The server does not support '{}' applications (supported: {})",
+            app_type,
+            supported.join(", ")
         );
-        let error_text = response.text().await?;
-        println!("Error: {}", error_text);
     }
 
     Ok(())
@@ -0,0 +1,66 @@
+use crate::api::client::NepheliosClient;
+use crate::types::manifest::Manifest;
+use crate::utils::plan::print_changes;
+use crate::utils::spinner::create_spinner;
+use anyhow::Result;
+use console::style;
+use std::path::Path;
+
+/// Exit code returned when the manifest differs from the deployed application.
+pub const EXIT_DIFFERENCES: i32 = 2;
+
+/// Executes the diff command to preview what `apply` would change.
+///
+/// Nothing is modified on the server.
+///
+/// # Arguments
+///
+/// * `file` - The path of the manifest (e.g., nephelios.toml)
+///
+/// # Returns
+///
+/// * `Ok(true)` if applying the manifest would create or update the application
+/// * `Ok(false)` if the application already matches the manifest
+/// * `Err(anyhow::Error)` if the manifest is invalid or the server request failed
+pub async fn execute(file: &Path) -> Result<bool> {
+    let manifest = Manifest::load(file)?;
+    let desired = manifest.to_spec(file)?;
+
+    let client = NepheliosClient::from_env()?;
+    let spinner = create_spinner(&format!("Fetching {} application...", desired.app_name));
+    let live = client.get_app(&desired.app_name).await;
+    spinner.finish_and_clear();
+
+    match live? {
+        None => {
+            println!(
+                "{} application {:?} will be created:",
+                style("create").green().bold(),
+                desired.app_name
+            );
+            print_changes(&desired.creation_changes());
+            Ok(true)
+        }
+        Some(live) => {
+            let changes = live.changes(&desired);
+            if changes.is_empty() {
+                println!(
+                    "{} application {:?} matches {}",
+                    style("no-op").dim().bold(),
+                    desired.app_name,
+                    file.display()
+                );
+                return Ok(false);
+            }
+
+            println!(
+                "{} application {:?} will be updated ({} changes):",
+                style("update").yellow().bold(),
+                desired.app_name,
+                changes.len()
+            );
+            print_changes(&changes);
+            Ok(true)
+        }
+    }
+}
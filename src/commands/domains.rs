@@ -0,0 +1,193 @@
+use crate::api::client::NepheliosClient;
+use crate::api::domains::{AcmeSettings, LETS_ENCRYPT_DIRECTORY};
+use crate::utils::config::ContextConfig;
+use crate::utils::domain::qualify_host;
+use crate::utils::git::ensure_secure_transport;
+use crate::utils::spinner::create_spinner;
+use anyhow::{bail, Context as _, Result};
+use std::fs;
+use std::path::Path;
+
+/// Routes a host name to an application, optionally with an ACME certificate.
+///
+/// With `tls`, the server requests a certificate from the ACME directory and
+/// renews it before it expires. The directory and contact address default to
+/// those of the context, then to Let's Encrypt.
+///
+/// # Arguments
+///
+/// * `app_name` - The name of the application
+/// * `host` - The host name, completed with the context's domain suffix when it is a single label
+/// * `tls` - Whether to serve the host over HTTPS with an ACME certificate
+/// * `acme_directory` - The ACME directory URL, overriding the context's
+/// * `acme_email` - The ACME contact address, overriding the context's
+/// * `context` - The active context, providing the domain suffix and ACME settings
+pub async fn add(
+    app_name: &str,
+    host: &str,
+    tls: bool,
+    acme_directory: Option<&str>,
+    acme_email: Option<&str>,
+    context: &ContextConfig,
+) -> Result<()> {
+    let host = qualify_host(host, context.domain_suffix.as_deref())?;
+    let acme = if tls {
+        Some(acme_settings(acme_directory, acme_email, context)?)
+    } else {
+        None
+    };
+
+    let client = NepheliosClient::from_env()?;
+    let spinner = create_spinner(&format!("Adding domain {}...", host));
+    let result = client.add_domain(app_name, &host, acme.as_ref()).await;
+    spinner.finish_and_clear();
+    let domain = result?;
+
+    println!("✅ Domain {:?} added to {:?}", domain.host, app_name);
+    println!("   - Access URL: {}", domain.url);
+    if let Some(acme) = &acme {
+        println!("   - ACME directory: {}", acme.directory);
+    }
+    if let Some(certificate) = &domain.certificate {
+        println!("   - Certificate: {}", certificate.summary());
+    }
+    Ok(())
+}
+
+/// Lists the domains of an application.
+///
+/// # Arguments
+///
+/// * `app_name` - The name of the application
+pub async fn list(app_name: &str) -> Result<()> {
+    let client = NepheliosClient::from_env()?;
+    let spinner = create_spinner(&format!("Fetching domains of {}...", app_name));
+    let result = client.domains(app_name).await;
+    spinner.finish_and_clear();
+    let domains = result?;
+
+    if domains.is_empty() {
+        println!("No domains for {:?}", app_name);
+        return Ok(());
+    }
+
+    println!("Domains of {:?}:", app_name);
+    for domain in domains {
+        println!("   - {} ({})", domain.host, domain.url);
+        match &domain.certificate {
+            Some(certificate) => println!("     Certificate: {}", certificate.summary()),
+            None => println!("     Certificate: none"),
+        }
+    }
+
+    Ok(())
+}
+
+/// Stops routing a host name to an application.
+///
+/// # Arguments
+///
+/// * `app_name` - The name of the application
+/// * `host` - The host name, completed with the context's domain suffix when it is a single label
+/// * `context` - The active context, providing the domain suffix
+pub async fn remove(app_name: &str, host: &str, context: &ContextConfig) -> Result<()> {
+    let host = qualify_host(host, context.domain_suffix.as_deref())?;
+
+    let client = NepheliosClient::from_env()?;
+    let spinner = create_spinner(&format!("Removing domain {}...", host));
+    let result = client.remove_domain(app_name, &host).await;
+    spinner.finish_and_clear();
+    result?;
+
+    println!("✅ Domain {:?} removed from {:?}", host, app_name);
+    Ok(())
+}
+
+/// Serves a certificate provided by the user for a domain of an application.
+///
+/// The certificate replaces any ACME certificate, and is not renewed by the server.
+///
+/// # Arguments
+///
+/// * `app_name` - The name of the application
+/// * `host` - The host name, completed with the context's domain suffix when it is a single label
+/// * `cert` - A PEM file holding the certificate chain
+/// * `key` - A PEM file holding the private key
+/// * `context` - The active context, providing the domain suffix
+pub async fn upload_certificate(
+    app_name: &str,
+    host: &str,
+    cert: &Path,
+    key: &Path,
+    context: &ContextConfig,
+) -> Result<()> {
+    let host = qualify_host(host, context.domain_suffix.as_deref())?;
+    let certificate = read_pem(cert, "CERTIFICATE")?;
+    let private_key = read_pem(key, "PRIVATE KEY")?;
+
+    let client = NepheliosClient::from_env()?;
+    ensure_secure_transport(client.base_url())?;
+
+    let spinner = create_spinner(&format!("Uploading certificate for {}...", host));
+    let result = client
+        .upload_certificate(app_name, &host, &certificate, &private_key)
+        .await;
+    spinner.finish_and_clear();
+    let certificate = result?;
+
+    println!("✅ Certificate uploaded for {:?}", host);
+    println!("   - Certificate: {}", certificate.summary());
+    Ok(())
+}
+
+/// Prints the URLs an application is reachable at, as reported by the server.
+///
+/// # Arguments
+///
+/// * `client` - The client used to fetch the domains
+/// * `app_name` - The name of the application
+pub async fn print_access_urls(client: &NepheliosClient, app_name: &str) {
+    match client.domains(app_name).await {
+        Ok(domains) if !domains.is_empty() => {
+            for domain in domains {
+                println!("   - Access URL: {}", domain.url);
+            }
+        }
+        Ok(_) => println!("   - Access URL: none, add one with `domains add`"),
+        Err(_) => println!("   - Access URL: not reported by the server"),
+    }
+}
+
+/// Resolves the ACME account from the flags, the context, then Let's Encrypt.
+fn acme_settings(
+    directory: Option<&str>,
+    email: Option<&str>,
+    context: &ContextConfig,
+) -> Result<AcmeSettings> {
+    let directory = directory
+        .or(context.acme_directory.as_deref())
+        .unwrap_or(LETS_ENCRYPT_DIRECTORY);
+    if !directory.starts_with("https://") {
+        bail!(
+            "The ACME directory must be an https:// URL, got {}",
+            directory
+        );
+    }
+
+    Ok(AcmeSettings {
+        directory: directory.to_string(),
+        email: email
+            .map(str::to_string)
+            .or_else(|| context.acme_email.clone()),
+    })
+}
+
+/// Reads a PEM file, checking that it holds the expected kind of block.
+fn read_pem(path: &Path, label: &str) -> Result<String> {
+    let content =
+        fs::read_to_string(path).with_context(|| format!("Failed to read {}", path.display()))?;
+    if !content.contains(&format!("{}-----", label)) {
+        bail!("{} does not contain a PEM {}", path.display(), label);
+    }
+    Ok(content)
+}
@@ -6,13 +6,9 @@ pub async fn execute() -> Result<(), anyhow::Error> {
     match docker {
         Ok(docker) => {
             let nephelios_service: NepheliosService = NepheliosService::new(docker, None, None);
-            match nephelios_service.stop().await {
-                Ok(_) => {
-                    println!("Nephelios stopped successfully");
-                    Ok(())
-                }
-                Err(e) => Err(e.into()),
-            }
+            nephelios_service.stop().await?;
+            println!("Nephelios stopped successfully");
+            Ok(())
         }
         Err(e) => {
             eprintln!("Failed to connect to Docker: {}", e);
@@ -0,0 +1,116 @@
+use crate::api::client::NepheliosClient;
+use crate::commands::restart;
+use crate::types::app_spec::MASK;
+use crate::utils::env_file::read_env_file;
+use crate::utils::spinner::create_spinner;
+use anyhow::{bail, Result};
+use std::collections::BTreeMap;
+use std::path::Path;
+
+/// Lists the environment variables of an application.
+///
+/// # Arguments
+///
+/// * `app_name` - The name of the application
+/// * `reveal` - Whether to print the values instead of masking them
+pub async fn list(app_name: &str, reveal: bool) -> Result<()> {
+    let client = NepheliosClient::from_env()?;
+    let spinner = create_spinner(&format!("Fetching environment of {}...", app_name));
+    let vars = client.get_env(app_name).await;
+    spinner.finish_and_clear();
+    let vars = vars?;
+
+    if vars.is_empty() {
+        println!("No environment variables set for {:?}", app_name);
+        return Ok(());
+    }
+
+    println!("Environment variables of {:?}:", app_name);
+    for (key, value) in vars {
+        println!(
+            "   - {}={}",
+            key,
+            if reveal { value.as_str() } else { MASK }
+        );
+    }
+
+    Ok(())
+}
+
+/// Sets environment variables of an application.
+///
+/// # Arguments
+///
+/// * `app_name` - The name of the application
+/// * `vars` - The variables to set, as key/value pairs
+/// * `restart` - Whether to restart the application so it picks up the new values
+pub async fn set(app_name: &str, vars: &[(String, String)], restart: bool) -> Result<()> {
+    let vars: BTreeMap<String, String> = vars.iter().cloned().collect();
+    update(app_name, &vars, restart).await
+}
+
+/// Sets the environment variables defined in a `.env` file.
+///
+/// # Arguments
+///
+/// * `app_name` - The name of the application
+/// * `file` - The path of the `.env` file
+/// * `restart` - Whether to restart the application so it picks up the new values
+pub async fn import(app_name: &str, file: &Path, restart: bool) -> Result<()> {
+    let vars = read_env_file(file)?;
+
+    if vars.is_empty() {
+        bail!("{} does not define any variable", file.display());
+    }
+
+    update(app_name, &vars, restart).await
+}
+
+/// Removes environment variables from an application.
+///
+/// # Arguments
+///
+/// * `app_name` - The name of the application
+/// * `keys` - The names of the variables to remove
+/// * `restart` - Whether to restart the application so it stops seeing the values
+pub async fn unset(app_name: &str, keys: &[String], restart: bool) -> Result<()> {
+    let client = NepheliosClient::from_env()?;
+    let spinner = create_spinner(&format!("Updating environment of {}...", app_name));
+    let result = client.unset_env(app_name, keys).await;
+    spinner.finish_and_clear();
+    result?;
+
+    println!("✅ Removed {} variable(s) from {:?}:", keys.len(), app_name);
+    for key in keys {
+        println!("   - {}", key);
+    }
+
+    maybe_restart(app_name, restart).await
+}
+
+async fn update(app_name: &str, vars: &BTreeMap<String, String>, restart: bool) -> Result<()> {
+    let client = NepheliosClient::from_env()?;
+    let spinner = create_spinner(&format!("Updating environment of {}...", app_name));
+    let result = client.set_env(app_name, vars).await;
+    spinner.finish_and_clear();
+    result?;
+
+    println!("✅ Set {} variable(s) on {:?}:", vars.len(), app_name);
+    for key in vars.keys() {
+        println!("   - {}={}", key, MASK);
+    }
+
+    maybe_restart(app_name, restart).await
+}
+
+async fn maybe_restart(app_name: &str, restart: bool) -> Result<()> {
+    if !restart {
+        println!(
+            "Changes apply on the next restart of {:?} (use --restart to restart now)",
+            app_name
+        );
+        return Ok(());
+    }
+
+    restart::execute(app_name, restart::RESTART_TIMEOUT).await
+}
@@ -0,0 +1,196 @@
+use crate::commands::apply;
+use crate::types::app_spec::{default_replicas, Resources};
+use crate::types::app_type::AppType;
+use crate::types::manifest::{Manifest, Source};
+use crate::utils::config::ContextConfig;
+use crate::utils::git::{origin_url, parse_repo_url};
+use anyhow::{bail, Result};
+use clap::ValueEnum;
+use dialoguer::theme::ColorfulTheme;
+use dialoguer::{Confirm, Input, Select};
+use std::collections::BTreeMap;
+use std::env;
+use std::fs;
+use std::path::Path;
+
+/// Executes the init command to scaffold a new application project.
+///
+/// Asks for the application name, type and repository, using the directory
+/// name, the detected project type and the `origin` remote as defaults, then
+/// writes the manifest. A starter Dockerfile can be added, and the application
+/// can be deployed right away.
+///
+/// # Arguments
+///
+/// * `file` - The path of the manifest to write (e.g., nephelios.toml)
+/// * `force` - Whether an existing manifest may be overwritten
+/// * `context` - The active context, used when deploying right away
+///
+/// # Returns
+///
+/// * `Ok(())` if the project was initialised
+/// * `Err(anyhow::Error)` if a prompt failed or a file could not be written
+pub async fn execute(file: &Path, force: bool, context: &ContextConfig) -> Result<()> {
+    if file.exists() && !force {
+        bail!(
+            "{} already exists. Use --force to overwrite it",
+            file.display()
+        );
+    }
+
+    let project_dir = match file.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+        Some(dir) => dir.to_path_buf(),
+        None => env::current_dir()?,
+    };
+    let theme = ColorfulTheme::default();
+
+    let default_name = project_dir
+        .canonicalize()
+        .ok()
+        .and_then(|dir| {
+            dir.file_name()
+                .map(|name| app_name_from(&name.to_string_lossy()))
+        })
+        .unwrap_or_default();
+    let name: String = Input::with_theme(&theme)
+        .with_prompt("Application name")
+        .default(default_name)
+        .validate_with(|name: &String| validate_app_name(name))
+        .interact_text()?;
+
+    let app_types = AppType::value_variants();
+    let detected = AppType::detect(&project_dir).ok();
+    let selected = Select::with_theme(&theme)
+        .with_prompt("Application type")
+        .items(app_types)
+        .default(
+            detected
+                .and_then(|detected| app_types.iter().position(|t| *t == detected))
+                .unwrap_or(0),
+        )
+        .interact()?;
+    let app_type = app_types[selected];
+
+    let mut repo_prompt = Input::<String>::with_theme(&theme)
+        .with_prompt("GitHub repository URL")
+        .validate_with(|url: &String| match parse_repo_url(url) {
+            Ok(_) => Ok(()),
+            Err(e) => Err(e.to_string()),
+        });
+    if let Some(origin) = origin_url(&project_dir) {
+        repo_prompt = repo_prompt.default(origin);
+    }
+    let github_url = repo_prompt.interact_text()?;
+
+    let manifest = Manifest {
+        name: name.clone(),
+        app_type: Some(app_type),
+        source: Source {
+            github_url,
+            git_ref: None,
+            path: None,
+        },
+        replicas: default_replicas(),
+        domains: vec![],
+        env: BTreeMap::new(),
+        resources: Resources::default(),
+    };
+    manifest.save(file)?;
+    println!("✅ Wrote {}", file.display());
+
+    let dockerfile_path = project_dir.join("Dockerfile");
+    if let Some(dockerfile) = starter_dockerfile(app_type, &name) {
+        if !dockerfile_path.exists()
+            && Confirm::with_theme(&theme)
+                .with_prompt(format!("Add a starter Dockerfile for {}?", app_type))
+                .default(false)
+                .interact()?
+        {
+            fs::write(&dockerfile_path, dockerfile)?;
+            println!("✅ Wrote {}", dockerfile_path.display());
+        }
+    }
+
+    if Confirm::with_theme(&theme)
+        .with_prompt("Deploy now?")
+        .default(false)
+        .interact()?
+    {
+        apply::execute(file, context).await?;
+    } else {
+        println!(
+            "Deploy later with: nephelios-cli apply -f {}",
+            file.display()
+        );
+    }
+
+    Ok(())
+}
+
+/// Turns a directory name into a valid application name.
+fn app_name_from(dir_name: &str) -> String {
+    dir_name
+        .to_lowercase()
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '-' })
+        .collect::<String>()
+        .trim_matches('-')
+        .to_string()
+}
+
+/// Checks that a name can be used as an application name.
+///
+/// Names end up in service names and subdomains, so only lowercase letters,
+/// digits and dashes are allowed.
+fn validate_app_name(name: &str) -> Result<(), String> {
+    if name.is_empty() {
+        return Err("The name cannot be empty".to_string());
+    }
+    if name.starts_with('-') || name.ends_with('-') {
+        return Err("The name cannot start or end with a dash".to_string());
+    }
+    if !name
+        .chars()
+        .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
+    {
+        return Err("Use only lowercase letters, digits and dashes".to_string());
+    }
+    Ok(())
+}
+
+/// Returns a starter Dockerfile for the given application type.
+fn starter_dockerfile(app_type: AppType, app_name: &str) -> Option<String> {
+    let dockerfile = match app_type {
+        AppType::Nodejs => "FROM node:20-alpine\n\
+             WORKDIR /app\n\
+             COPY package*.json ./\n\
+             RUN npm ci --omit=dev\n\
+             COPY . .\n\
+             EXPOSE 3000\n\
+             CMD [\"npm\", \"start\"]\n"
+            .to_string(),
+        AppType::Python => "FROM python:3.12-slim\n\
+             WORKDIR /app\n\
+             COPY requirements.txt ./\n\
+             RUN pip install --no-cache-dir -r requirements.txt\n\
+             COPY . .\n\
+             EXPOSE 8000\n\
+             CMD [\"python\", \"main.py\"]\n"
+            .to_string(),
+        AppType::Rust => format!(
+            "FROM rust:1-slim AS build\n\
+             WORKDIR /app\n\
+             COPY . .\n\
+             RUN cargo build --release\n\
+             \n\
+             FROM debian:bookworm-slim\n\
+             # Replace {0} with the name of your binary if it differs\n\
+             COPY --from=build /app/target/release/{0} /usr/local/bin/app\n\
+             CMD [\"app\"]\n",
+            app_name
+        ),
+        AppType::Docker => return None,
+    };
+
+    Some(dockerfile)
+}
@@ -0,0 +1,172 @@
+use crate::api::client::NepheliosClient;
+use crate::api::jobs::Job;
+use crate::utils::spinner::{create_progress_bar, create_spinner};
+use anyhow::{bail, Result};
+use dialoguer::Confirm;
+use std::time::{Duration, Instant};
+
+/// Delay between two job status checks.
+const POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Lists the jobs known to the server.
+pub async fn list() -> Result<()> {
+    let client = NepheliosClient::from_env()?;
+    let spinner = create_spinner("Fetching jobs...");
+    let result = client.jobs().await;
+    spinner.finish_and_clear();
+    let jobs = result?;
+
+    if jobs.is_empty() {
+        println!("No jobs");
+        return Ok(());
+    }
+
+    println!(
+        "{:<36}  {:<10}  {:<20}  {:<10}  {:<8}  CREATED",
+        "ID", "KIND", "APPLICATION", "STATUS", "PROGRESS"
+    );
+    for job in jobs {
+        println!(
+            "{:<36}  {:<10}  {:<20}  {:<10}  {:<8}  {}",
+            job.id,
+            job.kind,
+            job.app_name,
+            job.status,
+            job.progress
+                .map_or_else(|| "-".to_string(), |progress| format!("{}%", progress)),
+            job.created_at
+        );
+    }
+
+    Ok(())
+}
+
+/// Waits for a job to finish, showing its progress and current step.
+///
+/// # Arguments
+///
+/// * `job_id` - The ID of the job
+/// * `timeout` - How long to wait, or `None` to wait until the job finishes
+///
+/// # Returns
+///
+/// * `Ok(())` if the job succeeded
+/// * `Err(anyhow::Error)` if the job failed, was cancelled, or did not finish in time
+pub async fn wait(job_id: &str, timeout: Option<Duration>) -> Result<()> {
+    let client = NepheliosClient::from_env()?;
+    let started = Instant::now();
+    let bar = create_progress_bar(100, &format!("Waiting for job {}", job_id));
+
+    let job = loop {
+        let job = match client.job(job_id).await {
+            Ok(job) => job,
+            Err(e) => {
+                bar.finish_and_clear();
+                return Err(e);
+            }
+        };
+
+        if let Some(progress) = job.progress {
+            bar.set_position(progress.min(100) as u64);
+        }
+        if let Some(step) = &job.step {
+            bar.set_message(step.clone());
+        }
+
+        if job.is_finished() {
+            bar.finish_and_clear();
+            break job;
+        }
+
+        if timeout.is_some_and(|timeout| started.elapsed() >= timeout) {
+            bar.finish_and_clear();
+            bail!(
+                "Timed out waiting for job {} (status: {})",
+                job_id,
+                job.status
+            );
+        }
+
+        tokio::time::sleep(POLL_INTERVAL).await;
+    };
+
+    report(&job)
+}
+
+/// Cancels a queued or running job.
+///
+/// # Arguments
+///
+/// * `job_id` - The ID of the job
+pub async fn cancel(job_id: &str) -> Result<()> {
+    let client = NepheliosClient::from_env()?;
+    let spinner = create_spinner(&format!("Cancelling job {}...", job_id));
+    let result = client.cancel_job(job_id).await;
+    spinner.finish_and_clear();
+    result?;
+
+    println!("✅ Cancelled job {}", job_id);
+    Ok(())
+}
+
+/// Asks whether an interrupted deployment should keep running on the server.
+///
+/// Detaching leaves the job running so it can be followed with `jobs wait`;
+/// otherwise the job is cancelled.
+///
+/// # Arguments
+///
+/// * `client` - The client used to cancel the job
+/// * `app_name` - The name of the application being deployed
+/// * `job_id` - The ID of the deployment job, if the server reported one
+pub async fn offer_detach(
+    client: &NepheliosClient,
+    app_name: &str,
+    job_id: Option<&str>,
+) -> Result<()> {
+    let Some(job_id) = job_id else {
+        bail!(
+            "Interrupted. The server did not report a job for the deployment of {}, \
+             so it may stop with the connection",
+            app_name
+        );
+    };
+
+    let detach = Confirm::new()
+        .with_prompt(format!(
+            "Keep deploying {} in the background? (no cancels the deployment)",
+            app_name
+        ))
+        .default(true)
+        .interact()?;
+
+    if detach {
+        println!("✅ Detached from the deployment of {:?}", app_name);
+        println!("   - Job ID: {}", job_id);
+        println!("   - Follow it with: nephelios-cli jobs wait {}", job_id);
+        return Ok(());
+    }
+
+    client.cancel_job(job_id).await?;
+    println!("✅ Cancelled the deployment of {:?}", app_name);
+    Ok(())
+}
+
+fn report(job: &Job) -> Result<()> {
+    if job.is_succeeded() {
+        println!("✅ Job {} succeeded", job.id);
+        if !job.app_name.is_empty() {
+            println!("   - Application Name: {}", job.app_name);
+        }
+        return Ok(());
+    }
+
+    if job.status == "cancelled" {
+        bail!("Job {} was cancelled", job.id);
+    }
+    bail!(
+        "Job {} failed: {}",
+        job.id,
+        job.error.as_deref().unwrap_or("no error reported")
+    )
+}
@@ -1,6 +1,24 @@
+pub mod apply;
+pub mod auth;
+pub mod context;
 pub mod create;
+pub mod diff;
+pub mod domains;
 pub mod down;
+pub mod env;
+pub mod init;
+pub mod jobs;
+pub mod platform;
+pub mod redeploy;
+pub mod releases;
 pub mod remove;
+pub mod restart;
+pub mod rollback;
+pub mod scale;
+pub mod secret;
 pub mod start;
 pub mod stop;
+pub mod token;
 pub mod up;
+pub mod users;
+pub mod version;
@@ -0,0 +1,29 @@
+use crate::api::client::NepheliosClient;
+use crate::docker::nephelios_service::NepheliosService;
+use crate::utils::spinner::create_spinner;
+use crate::utils::wait::wait_for_platform;
+use anyhow::{Context as _, Result};
+use bollard::Docker;
+use std::time::Duration;
+
+/// Restarts the Nephelios container and waits until its API answers again.
+///
+/// # Arguments
+///
+/// * `timeout` - How long to wait for Nephelios to be healthy after the restart
+pub async fn restart(timeout: Duration) -> Result<()> {
+    let docker = Docker::connect_with_local_defaults().context("Failed to connect to Docker")?;
+    let nephelios_service = NepheliosService::new(docker, None, None);
+
+    let spinner = create_spinner("Restarting Nephelios...");
+    let result = nephelios_service.restart().await;
+    spinner.finish_and_clear();
+    result?;
+
+    let client = NepheliosClient::from_env()?;
+    wait_for_platform(&nephelios_service, &client, timeout).await?;
+
+    println!("✅ Nephelios restarted successfully");
+    println!("   - API: {}", client.base_url());
+    Ok(())
+}
@@ -0,0 +1,47 @@
+use crate::api::build::follow_build;
+use crate::api::client::NepheliosClient;
+use crate::utils::build_log::BuildLog;
+use crate::utils::spinner::create_spinner;
+use anyhow::Result;
+
+/// Executes the redeploy command to rebuild an application from its repository.
+///
+/// The server builds the latest commit of the stored GitHub URL, or `git_ref`,
+/// and updates the service without downtime. The build output is streamed to
+/// the terminal.
+///
+/// # Arguments
+///
+/// * `app_name` - The name of the application to redeploy
+/// * `git_ref` - The branch, tag or commit to build
+///
+/// # Returns
+///
+/// * `Ok(())` if the new build is deployed
+/// * `Err(anyhow::Error)` if the build or the service update failed
+pub async fn execute(app_name: &str, git_ref: Option<&str>) -> Result<()> {
+    let client = NepheliosClient::from_env()?;
+    let spinner = create_spinner(&format!("Redeploying {} application...", app_name));
+
+    let response = match client.redeploy(app_name, git_ref).await {
+        Ok(response) => response,
+        Err(e) => {
+            spinner.finish_and_clear();
+            return Err(e);
+        }
+    };
+
+    let mut log = BuildLog::new(app_name, spinner);
+    let streamed = follow_build(response, |event| log.handle(event)).await;
+    let message = log.finish(streamed)?;
+
+    println!("✅ Redeployed app successfully: {:?}", app_name);
+    if let Some(git_ref) = git_ref {
+        println!("   - Ref: {}", git_ref);
+    }
+    if let Some(message) = message {
+        println!("   - Message: {}", message);
+    }
+
+    Ok(())
+}
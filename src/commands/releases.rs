@@ -0,0 +1,43 @@
+use crate::api::client::NepheliosClient;
+use crate::utils::spinner::create_spinner;
+use anyhow::Result;
+
+/// Executes the releases command to list the release history of an application.
+///
+/// # Arguments
+///
+/// * `app_name` - The name of the application
+///
+/// # Returns
+///
+/// * `Ok(())` if the history was printed
+/// * `Err(anyhow::Error)` if the server request failed
+pub async fn execute(app_name: &str) -> Result<()> {
+    let client = NepheliosClient::from_env()?;
+    let spinner = create_spinner(&format!("Fetching releases of {}...", app_name));
+    let releases = client.releases(app_name).await;
+    spinner.finish_and_clear();
+    let releases = releases?;
+
+    if releases.is_empty() {
+        println!("No releases for {:?}", app_name);
+        return Ok(());
+    }
+
+    println!(
+        "{:<8} {:<9} {:<25} {:<12} IMAGE",
+        "RELEASE", "COMMIT", "CREATED", "STATUS"
+    );
+    for release in &releases {
+        println!(
+            "{:<8} {:<9} {:<25} {:<12} {}",
+            format!("#{}", release.number),
+            release.short_commit(),
+            release.created_at,
+            release.status,
+            release.image_digest
+        );
+    }
+
+    Ok(())
+}
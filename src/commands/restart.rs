@@ -0,0 +1,38 @@
+use crate::api::client::NepheliosClient;
+use crate::utils::rollout::run_and_wait;
+use anyhow::Result;
+use std::time::Duration;
+
+/// How long to wait for the restarted replicas to run, unless told otherwise.
+pub const RESTART_TIMEOUT: Duration = Duration::from_secs(300);
+
+/// Executes the restart command to restart the replicas of an application one at a time.
+///
+/// # Arguments
+///
+/// * `app_name` - The name of the application to restart
+/// * `timeout` - How long to wait for all replicas to be replaced
+///
+/// # Returns
+///
+/// * `Ok(())` if every replica was replaced by a running one
+/// * `Err(anyhow::Error)` if the server refused, the update was aborted, or the
+///   new replicas did not start in time
+pub async fn execute(app_name: &str, timeout: Duration) -> Result<()> {
+    let client = NepheliosClient::from_env()?;
+    let status = run_and_wait(
+        &client,
+        app_name,
+        &format!("Restarting {} application...", app_name),
+        || client.restart_app(app_name),
+        timeout,
+    )
+    .await?;
+    println!("✅ Restarted app successfully: {:?}", app_name);
+    println!(
+        "   - Running replicas: {}/{}",
+        status.running, status.replicas
+    );
+
+    Ok(())
+}
@@ -0,0 +1,94 @@
+use crate::api::client::NepheliosClient;
+use crate::api::releases::Release;
+use crate::utils::rollout::run_and_wait;
+use crate::utils::spinner::create_spinner;
+use anyhow::{anyhow, bail, Result};
+use std::time::Duration;
+
+/// How long to wait for the rolled back release to run.
+const ROLLBACK_TIMEOUT: Duration = Duration::from_secs(300);
+
+/// Executes the rollback command to redeploy a previous release of an application.
+///
+/// The image of the release is taken from the platform registry, so nothing is rebuilt.
+///
+/// # Arguments
+///
+/// * `app_name` - The name of the application
+/// * `to` - The release to redeploy, defaulting to the last good release before the active one
+///
+/// # Returns
+///
+/// * `Ok(())` if the release is running again
+/// * `Err(anyhow::Error)` if no suitable release exists or the rollback failed
+pub async fn execute(app_name: &str, to: Option<u32>) -> Result<()> {
+    let client = NepheliosClient::from_env()?;
+
+    let spinner = create_spinner(&format!("Fetching releases of {}...", app_name));
+    let releases = client.releases(app_name).await;
+    spinner.finish_and_clear();
+    let releases = releases?;
+
+    let target = select_target(&releases, to)?;
+    run_and_wait(
+        &client,
+        app_name,
+        &format!(
+            "Rolling back {} to release #{} ({})...",
+            app_name,
+            target.number,
+            target.short_commit()
+        ),
+        || client.rollback(app_name, target.number),
+        ROLLBACK_TIMEOUT,
+    )
+    .await?;
+
+    println!(
+        "✅ Rolled back {:?} to release #{}",
+        app_name, target.number
+    );
+    println!("   - Commit: {}", target.commit);
+    println!("   - Image: {}", target.image_digest);
+    Ok(())
+}
+
+/// Picks the release to roll back to.
+///
+/// # Arguments
+///
+/// * `releases` - The releases of the application, in any order
+/// * `to` - The release requested with `--to`, if any
+///
+/// # Returns
+///
+/// * `Ok(&Release)` with the requested release, or the newest non-failed release
+///   older than the active one
+/// * `Err(anyhow::Error)` if the release does not exist, is active, failed, or
+///   there is nothing to roll back to
+pub fn select_target(releases: &[Release], to: Option<u32>) -> Result<&Release> {
+    if let Some(number) = to {
+        let release = releases
+            .iter()
+            .find(|release| release.number == number)
+            .ok_or_else(|| anyhow!("Release #{} does not exist", number))?;
+        if release.is_active() {
+            bail!("Release #{} is already active", number);
+        }
+        if release.is_failed() {
+            bail!("Release #{} failed and cannot be redeployed", number);
+        }
+        return Ok(release);
+    }
+
+    let active = releases
+        .iter()
+        .find(|release| release.is_active())
+        .map_or(u32::MAX, |release| release.number);
+
+    releases
+        .iter()
+        .filter(|release| release.number < active && !release.is_failed())
+        .max_by_key(|release| release.number)
+        .ok_or_else(|| anyhow!("There is no previous release to roll back to"))
+}
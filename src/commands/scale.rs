@@ -0,0 +1,61 @@
+use crate::api::client::NepheliosClient;
+use crate::utils::spinner::create_spinner;
+use crate::utils::wait::wait_for_replicas;
+use anyhow::{bail, Result};
+use std::time::Duration;
+
+/// Executes the scale command to change the number of replicas of an application.
+///
+/// # Arguments
+///
+/// * `app_name` - The name of the application to scale
+/// * `replicas` - The number of replicas to run
+/// * `min` - The lower autoscaling bound, for servers that support autoscaling
+/// * `max` - The upper autoscaling bound, for servers that support autoscaling
+/// * `wait_timeout` - How long to wait for all tasks to run, or `None` to return immediately
+///
+/// # Returns
+///
+/// * `Ok(())` if the application was scaled
+/// * `Err(anyhow::Error)` if the bounds are invalid, the server refused, or the tasks did not start in time
+pub async fn execute(
+    app_name: &str,
+    replicas: Option<u32>,
+    min: Option<u32>,
+    max: Option<u32>,
+    wait_timeout: Option<Duration>,
+) -> Result<()> {
+    if let (Some(min), Some(max)) = (min, max) {
+        if min > max {
+            bail!("--min ({}) cannot be greater than --max ({})", min, max);
+        }
+    }
+    if let Some(replicas) = replicas {
+        if min.is_some_and(|min| replicas < min) || max.is_some_and(|max| replicas > max) {
+            bail!("--replicas ({}) must be between --min and --max", replicas);
+        }
+    }
+
+    let client = NepheliosClient::from_env()?;
+    let spinner = create_spinner(&format!("Scaling {} application...", app_name));
+    let result = client.scale_app(app_name, replicas, min, max).await;
+    spinner.finish_and_clear();
+    result?;
+
+    let Some(timeout) = wait_timeout else {
+        println!("✅ Scale request accepted for {:?}", app_name);
+        return Ok(());
+    };
+
+    let status = wait_for_replicas(&client, app_name, timeout).await?;
+    println!("✅ Scaled app successfully: {:?}", app_name);
+    println!(
+        "   - Running replicas: {}/{}",
+        status.running, status.replicas
+    );
+    if let (Some(min), Some(max)) = (status.min_replicas, status.max_replicas) {
+        println!("   - Autoscaling: {} to {} replicas", min, max);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,169 @@
+use crate::api::client::NepheliosClient;
+use crate::api::secrets::validate_secret_name;
+use crate::utils::git::ensure_secure_transport;
+use crate::utils::spinner::create_spinner;
+use anyhow::{bail, Context as _, Result};
+use dialoguer::Password;
+use std::fs;
+use std::io::{self, IsTerminal, Read};
+use std::path::Path;
+
+/// Creates a secret for an application, optionally mounting it right away.
+///
+/// The value is read from `from_file`, from standard input when it is piped,
+/// or from a hidden prompt. It is never printed.
+///
+/// # Arguments
+///
+/// * `app_name` - The name of the application
+/// * `secret` - The name of the secret (e.g., DB_PASSWORD)
+/// * `from_file` - A file holding the secret value
+/// * `attach` - Whether to mount the secret in the application's service
+pub async fn create(
+    app_name: &str,
+    secret: &str,
+    from_file: Option<&Path>,
+    attach: bool,
+) -> Result<()> {
+    validate_secret_name(secret)?;
+    let client = NepheliosClient::from_env()?;
+    ensure_secure_transport(client.base_url())?;
+
+    let value = read_value(secret, from_file)?;
+    if value.is_empty() {
+        bail!("The value of secret {} is empty", secret);
+    }
+
+    let spinner = create_spinner(&format!("Creating secret {}...", secret));
+    let result = client.create_secret(app_name, secret, &value).await;
+    spinner.finish_and_clear();
+    result?;
+    println!("✅ Created secret {:?} for {:?}", secret, app_name);
+
+    if attach {
+        attach_secret(&client, app_name, secret, None).await?;
+    }
+
+    Ok(())
+}
+
+/// Lists the secrets of an application, without their values.
+///
+/// # Arguments
+///
+/// * `app_name` - The name of the application
+pub async fn list(app_name: &str) -> Result<()> {
+    let client = NepheliosClient::from_env()?;
+    let spinner = create_spinner(&format!("Fetching secrets of {}...", app_name));
+    let secrets = client.list_secrets(app_name).await;
+    spinner.finish_and_clear();
+    let secrets = secrets?;
+
+    if secrets.is_empty() {
+        println!("No secrets for {:?}", app_name);
+        return Ok(());
+    }
+
+    println!("Secrets of {:?}:", app_name);
+    for secret in secrets {
+        println!(
+            "   - {} (created: {}, {})",
+            secret.name,
+            secret.created_at.as_deref().unwrap_or("unknown"),
+            if secret.attached {
+                "attached"
+            } else {
+                "not attached"
+            }
+        );
+    }
+
+    Ok(())
+}
+
+/// Removes a secret, detaching it from the application first.
+///
+/// # Arguments
+///
+/// * `app_name` - The name of the application
+/// * `secret` - The name of the secret
+pub async fn remove(app_name: &str, secret: &str) -> Result<()> {
+    validate_secret_name(secret)?;
+    let client = NepheliosClient::from_env()?;
+    let spinner = create_spinner(&format!("Removing secret {}...", secret));
+    let result = client.remove_secret(app_name, secret).await;
+    spinner.finish_and_clear();
+    result?;
+
+    println!("✅ Removed secret {:?} from {:?}", secret, app_name);
+    Ok(())
+}
+
+/// Mounts a secret in the application's service.
+///
+/// # Arguments
+///
+/// * `app_name` - The name of the application
+/// * `secret` - The name of the secret
+/// * `target` - The file name under `/run/secrets`, defaulting to the secret name
+pub async fn attach(app_name: &str, secret: &str, target: Option<&str>) -> Result<()> {
+    validate_secret_name(secret)?;
+    if let Some(target) = target {
+        validate_secret_name(target)?;
+    }
+    let client = NepheliosClient::from_env()?;
+    attach_secret(&client, app_name, secret, target).await
+}
+
+async fn attach_secret(
+    client: &NepheliosClient,
+    app_name: &str,
+    secret: &str,
+    target: Option<&str>,
+) -> Result<()> {
+    let spinner = create_spinner(&format!("Attaching secret {} to {}...", secret, app_name));
+    let result = client.attach_secret(app_name, secret, target).await;
+    spinner.finish_and_clear();
+
+    if result? {
+        println!(
+            "✅ Secret {:?} mounted at /run/secrets/{}",
+            secret,
+            target.unwrap_or(secret)
+        );
+    } else {
+        println!("Secret {:?} is already attached to {:?}", secret, app_name);
+    }
+
+    Ok(())
+}
+
+fn read_value(secret: &str, from_file: Option<&Path>) -> Result<Vec<u8>> {
+    if let Some(path) = from_file {
+        return fs::read(path).with_context(|| format!("Failed to read {}", path.display()));
+    }
+
+    if !io::stdin().is_terminal() {
+        let mut value = Vec::new();
+        io::stdin().read_to_end(&mut value)?;
+        return Ok(strip_trailing_newline(value));
+    }
+
+    let value = Password::new()
+        .with_prompt(format!("Value of {}", secret))
+        .with_confirmation("Confirm value", "The values do not match")
+        .interact()?;
+    Ok(value.into_bytes())
+}
+
+/// Removes the line ending that `echo` adds after a value piped on stdin.
+///
+/// Only one `\n` or `\r\n` is removed, so values that end with blank lines keep them.
+pub fn strip_trailing_newline(mut value: Vec<u8>) -> Vec<u8> {
+    if value.ends_with(b"\r\n") {
+        value.truncate(value.len() - 2);
+    } else if value.ends_with(b"\n") {
+        value.pop();
+    }
+    value
+}
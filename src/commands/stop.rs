@@ -1,8 +1,7 @@
+use crate::api::client::NepheliosClient;
 use crate::utils::spinner::create_spinner;
 use anyhow::Result;
-use reqwest::Client;
 use serde_json::json;
-use std::env;
 
 
 
@@ -17,18 +16,9 @@ use std::env;
 ///
 /// * `Ok(())` if stopping the application was successful.
 /// * `Err(anyhow::Error)` if there was an error during the stop process.
-
 pub async fn execute(app_name: &str) -> Result<()> {
 
-    let client = Client::new();
-    dotenv::dotenv().ok();
-
-    let nephelios_port: u16 = env::var("NEPHELIOS_PORT")
-        .unwrap_or_else(|_| "3030".to_string())
-        .parse()
-        .unwrap_or(3030);
-    let nephelios_url =
-        env::var("NEPHELIOS_URL").unwrap_or_else(|_| "http://localhost".to_string());
+    let client = NepheliosClient::from_env()?;
     let spinner = create_spinner(&format!("Stoping {} application...", app_name));
 
     let payload = json!({
@@ -36,8 +26,8 @@ pub async fn execute(app_name: &str) -> Result<()> {
     });
 
     let response = client
-        .post(format!("{}:{}/stop", nephelios_url, nephelios_port))
-        .header("Content-Type", "application/json")
+        .post("/stop")
+        .idempotent()
         .json(&payload)
         .send()
         .await?;
@@ -45,16 +35,8 @@ pub async fn execute(app_name: &str) -> Result<()> {
     // Stop the spinner
     spinner.finish_and_clear();
 
-    if response.status().is_success() {
-        println!("✅ Stoped app successfully: {:?}", app_name);
-    } else {
-        println!(
-            "❌ Failed to stop app. Status: {}",
-            response.status()
-        );
-        let error_text = response.text().await?;
-        println!("Error: {}", error_text);
-    }
+    NepheliosClient::check(response, &format!("stop {}", app_name)).await?;
+    println!("✅ Stoped app successfully: {:?}", app_name);
 
     Ok(())
 }
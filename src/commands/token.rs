@@ -0,0 +1,93 @@
+use crate::api::client::NepheliosClient;
+use crate::utils::spinner::create_spinner;
+use anyhow::Result;
+use std::io::{self, IsTerminal};
+use std::time::Duration;
+
+/// Creates a scoped API token and shows its secret, once.
+///
+/// When the output is not a terminal, only the secret is printed so that it
+/// can be captured by a script.
+///
+/// # Arguments
+///
+/// * `name` - A description of what the token is used for
+/// * `scopes` - What the token is allowed to do (e.g., deploy)
+/// * `expires_in` - How long the token is valid, or `None` for the server default
+pub async fn create(
+    name: Option<&str>,
+    scopes: &[String],
+    expires_in: Option<Duration>,
+) -> Result<()> {
+    let client = NepheliosClient::from_env()?;
+    let spinner = create_spinner("Creating token...");
+    let result = client.create_token(name, scopes, expires_in).await;
+    spinner.finish_and_clear();
+    let created = result?;
+
+    if !io::stdout().is_terminal() {
+        println!("{}", created.secret);
+        return Ok(());
+    }
+
+    println!("✅ Token created:");
+    println!("   - ID: {}", created.token.id);
+    println!("   - Scopes: {}", created.token.scopes.join(", "));
+    println!(
+        "   - Expires: {}",
+        created.token.expires_at.as_deref().unwrap_or("never")
+    );
+    println!();
+    println!("{}", created.secret);
+    println!();
+    println!("Copy the token now, it will not be shown again. Use it with NEPHELIOS_TOKEN.");
+    Ok(())
+}
+
+/// Lists the API tokens of the current user, without their secrets.
+pub async fn list() -> Result<()> {
+    let client = NepheliosClient::from_env()?;
+    let spinner = create_spinner("Fetching tokens...");
+    let result = client.tokens().await;
+    spinner.finish_and_clear();
+    let tokens = result?;
+
+    if tokens.is_empty() {
+        println!("No tokens");
+        return Ok(());
+    }
+
+    println!(
+        "{:<24}  {:<20}  {:<16}  {:<20}  {:<20}  LAST USED",
+        "ID", "NAME", "SCOPES", "CREATED", "EXPIRES"
+    );
+    for token in tokens {
+        println!(
+            "{:<24}  {:<20}  {:<16}  {:<20}  {:<20}  {}",
+            token.id,
+            token.name.as_deref().unwrap_or("-"),
+            token.scopes.join(","),
+            token.created_at,
+            token.expires_at.as_deref().unwrap_or("never"),
+            token.last_used_at.as_deref().unwrap_or("never")
+        );
+    }
+
+    Ok(())
+}
+
+/// Revokes an API token.
+///
+/// # Arguments
+///
+/// * `id` - The ID of the token, as shown by `token list`
+pub async fn revoke(id: &str) -> Result<()> {
+    let client = NepheliosClient::from_env()?;
+    let spinner = create_spinner(&format!("Revoking token {}...", id));
+    let result = client.revoke_token(id).await;
+    spinner.finish_and_clear();
+    result?;
+
+    println!("✅ Revoked token {}", id);
+    Ok(())
+}
@@ -19,7 +19,7 @@ pub async fn execute() -> Result<(), anyhow::Error> {
                         Ok(_) => println!("Image {} pulled successfully", version),
                         Err(e) => {
                             eprintln!("Failed to pull image {}: {}", version, e);
-                            return Err(e.into());
+                            return Err(e);
                         }
                     }
 
@@ -27,7 +27,7 @@ pub async fn execute() -> Result<(), anyhow::Error> {
                         Ok(_) => println!("Volumes checked/created successfully"),
                         Err(e) => {
                             eprintln!("Failed to check/create volumes: {}", e);
-                            return Err(e.into());
+                            return Err(e);
                         }
                     }
 
@@ -35,7 +35,7 @@ pub async fn execute() -> Result<(), anyhow::Error> {
                         Ok(_) => println!("Nephelios started successfully"),
                         Err(e) => {
                             eprintln!("Failed to start Nephelios: {}", e);
-                            return Err(e.into());
+                            return Err(e);
                         }
                     }
                 }
@@ -46,7 +46,7 @@ pub async fn execute() -> Result<(), anyhow::Error> {
                     Ok(_) => println!("Nephelios started successfully"),
                     Err(e) => {
                         eprintln!("Failed to start Nephelios: {}", e);
-                        return Err(e.into());
+                        return Err(e);
                     }
                 }
             } else {
@@ -0,0 +1,143 @@
+use crate::api::client::NepheliosClient;
+use crate::types::role::Role;
+use crate::utils::spinner::create_spinner;
+use anyhow::Result;
+
+/// Lists the users of the platform, or the roles granted on one application.
+///
+/// # Arguments
+///
+/// * `app_name` - The application whose grants to list, or `None` for all users
+pub async fn list(app_name: Option<&str>) -> Result<()> {
+    let client = NepheliosClient::from_env()?;
+
+    if let Some(app_name) = app_name {
+        let spinner = create_spinner(&format!("Fetching permissions of {}...", app_name));
+        let result = client.grants(app_name).await;
+        spinner.finish_and_clear();
+        let grants = result?;
+
+        if grants.is_empty() {
+            println!("No permissions granted on {:?}", app_name);
+            return Ok(());
+        }
+
+        println!("Permissions on {:?}:", app_name);
+        for grant in grants {
+            println!("   - {} ({})", grant.username, grant.role);
+        }
+        return Ok(());
+    }
+
+    let spinner = create_spinner("Fetching users...");
+    let result = client.users().await;
+    spinner.finish_and_clear();
+    let users = result?;
+
+    println!(
+        "{:<20}  {:<30}  {:<10}  STATUS",
+        "USERNAME", "EMAIL", "ROLE"
+    );
+    for user in users {
+        println!(
+            "{:<20}  {:<30}  {:<10}  {}",
+            user.username,
+            user.email.as_deref().unwrap_or("-"),
+            user.role.as_deref().unwrap_or("-"),
+            user.status.as_deref().unwrap_or("active")
+        );
+    }
+
+    Ok(())
+}
+
+/// Invites a new user with a platform role.
+///
+/// # Arguments
+///
+/// * `email` - The address to send the invitation to
+/// * `role` - The platform role of the new user
+pub async fn invite(email: &str, role: Role) -> Result<()> {
+    let client = NepheliosClient::from_env()?;
+    let spinner = create_spinner(&format!("Inviting {}...", email));
+    let result = client.invite_user(email, role).await;
+    spinner.finish_and_clear();
+    let invitation = result?;
+
+    println!("✅ Invited {:?} as {}", invitation.email, role);
+    if let Some(url) = &invitation.invite_url {
+        println!("   - Invitation link: {}", url);
+    }
+    Ok(())
+}
+
+/// Removes a user from the platform.
+///
+/// # Arguments
+///
+/// * `username` - The user to remove
+pub async fn remove(username: &str) -> Result<()> {
+    let client = NepheliosClient::from_env()?;
+    let spinner = create_spinner(&format!("Removing user {}...", username));
+    let result = client.remove_user(username).await;
+    spinner.finish_and_clear();
+    result?;
+
+    println!("✅ Removed user {:?}", username);
+    Ok(())
+}
+
+/// Changes the platform role of a user.
+///
+/// # Arguments
+///
+/// * `username` - The user whose role to change
+/// * `role` - The new platform role
+pub async fn set_role(username: &str, role: Role) -> Result<()> {
+    let client = NepheliosClient::from_env()?;
+    let spinner = create_spinner(&format!("Updating the role of {}...", username));
+    let result = client.set_user_role(username, role).await;
+    spinner.finish_and_clear();
+    result?;
+
+    println!("✅ {:?} is now {}", username, role);
+    Ok(())
+}
+
+/// Grants a user a role on a single application.
+///
+/// # Arguments
+///
+/// * `username` - The user to grant the role to
+/// * `app_name` - The application the role applies to
+/// * `role` - The role on the application
+pub async fn grant(username: &str, app_name: &str, role: Role) -> Result<()> {
+    let client = NepheliosClient::from_env()?;
+    let spinner = create_spinner(&format!("Granting {} access to {}...", username, app_name));
+    let result = client.grant(app_name, username, role).await;
+    spinner.finish_and_clear();
+    result?;
+
+    println!("✅ {:?} is now {} on {:?}", username, role, app_name);
+    Ok(())
+}
+
+/// Revokes the role of a user on a single application.
+///
+/// # Arguments
+///
+/// * `username` - The user whose access to revoke
+/// * `app_name` - The application the role applied to
+pub async fn revoke(username: &str, app_name: &str) -> Result<()> {
+    let client = NepheliosClient::from_env()?;
+    let spinner = create_spinner(&format!(
+        "Revoking the access of {} to {}...",
+        username, app_name
+    ));
+    let result = client.revoke_grant(app_name, username).await;
+    spinner.finish_and_clear();
+    result?;
+
+    println!("✅ Revoked the access of {:?} to {:?}", username, app_name);
+    Ok(())
+}
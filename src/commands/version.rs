@@ -0,0 +1,67 @@
+use crate::api::client::NepheliosClient;
+use crate::api::version::API_VERSION;
+use crate::docker::nephelios_service::NepheliosService;
+use crate::utils::spinner::create_spinner;
+use anyhow::Result;
+use bollard::Docker;
+
+/// Shows the versions of the CLI, of the server and of the platform image,
+/// and whether the CLI and the server speak the same API.
+pub async fn execute() -> Result<()> {
+    let client = NepheliosClient::from_env()?;
+
+    let spinner = create_spinner("Fetching versions...");
+    let server = client.server_version().await;
+    let image = match Docker::connect_with_local_defaults() {
+        Ok(docker) => NepheliosService::new(docker, None, None).image().await,
+        Err(e) => Err(e.into()),
+    };
+    spinner.finish_and_clear();
+
+    println!("✅ Nephelios versions:");
+    println!(
+        "   - CLI: {} (API version {})",
+        env!("CARGO_PKG_VERSION"),
+        API_VERSION
+    );
+
+    let mismatch = match &server {
+        Ok(Some(server)) => {
+            println!(
+                "   - Server: {} (API version {}) at {}",
+                server.version,
+                server.api_version,
+                client.base_url()
+            );
+            server.mismatch()
+        }
+        Ok(None) => {
+            println!(
+                "   - Server: unknown, it does not report its version, at {}",
+                client.base_url()
+            );
+            None
+        }
+        Err(e) => {
+            println!("   - Server: unreachable at {} ({})", client.base_url(), e);
+            None
+        }
+    };
+
+    match image {
+        Ok(Some(image)) => {
+            println!("   - Platform image: {}", image.reference);
+            println!(
+                "   - Image digest: {}",
+                image.digest.as_deref().unwrap_or(&image.id)
+            );
+        }
+        Ok(None) => println!("   - Platform image: not deployed on this machine"),
+        Err(_) => println!("   - Platform image: unknown, Docker is not available"),
+    }
+
+    if let Some(mismatch) = mismatch {
+        println!("⚠️  {}", mismatch);
+    }
+    Ok(())
+}
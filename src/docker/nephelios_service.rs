@@ -1,7 +1,9 @@
 use bollard::container::{
-    Config, CreateContainerOptions, ListContainersOptions, StartContainerOptions,
+    Config, CreateContainerOptions, ListContainersOptions, RestartContainerOptions,
+    StartContainerOptions,
 };
 use bollard::image::CreateImageOptions;
+use bollard::models::HealthStatusEnum;
 use bollard::Docker;
 use futures_util::stream::StreamExt;
 use std::collections::HashMap;
@@ -10,6 +12,18 @@ use std::result::Result::Ok;
 
 use super::volumes::nephelios_volume::NepheliosVolume;
 
+/// Image the Nephelios container runs.
+pub struct PlatformImage {
+    /// The image the container was created from (e.g., zuhowks/nephelios:latest).
+    pub reference: String,
+
+    /// The local ID of the image.
+    pub id: String,
+
+    /// The registry digest of the image, when it was pulled from a registry.
+    pub digest: Option<String>,
+}
+
 pub struct NepheliosService {
     pub docker: Docker,
     pub name: String,
@@ -92,7 +106,6 @@ impl NepheliosService {
     pub async fn start(&self) -> Result<(), anyhow::Error> {
         let option = StartContainerOptions {
             detach_keys: "ctrl-d",
-            ..Default::default()
         };
 
         let start_stream = self
@@ -111,6 +124,79 @@ impl NepheliosService {
             .map_err(|e| anyhow::anyhow!("Failed to stop container: {}", e))
     }
 
+    /// Restarts the container, giving it 10 seconds to stop gracefully.
+    pub async fn restart(&self) -> Result<(), anyhow::Error> {
+        self.docker
+            .restart_container(self.name.as_str(), Some(RestartContainerOptions { t: 10 }))
+            .await
+            .map_err(|e| anyhow::anyhow!("Failed to restart container: {}", e))
+    }
+
+    /// Checks whether the container is running and, when its image defines a
+    /// health check, reported healthy.
+    pub async fn is_healthy(&self) -> Result<bool, anyhow::Error> {
+        let container = self
+            .docker
+            .inspect_container(self.name.as_str(), None)
+            .await
+            .map_err(|e| anyhow::anyhow!("Failed to inspect container: {}", e))?;
+
+        let Some(state) = container.state else {
+            return Ok(false);
+        };
+        if state.running != Some(true) {
+            return Ok(false);
+        }
+
+        Ok(matches!(
+            state.health.and_then(|health| health.status),
+            None | Some(HealthStatusEnum::EMPTY)
+                | Some(HealthStatusEnum::NONE)
+                | Some(HealthStatusEnum::HEALTHY)
+        ))
+    }
+
+    /// Describes the image the Nephelios container runs.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Some(PlatformImage))` if the container exists
+    /// * `Ok(None)` if Nephelios is not deployed on this machine
+    /// * `Err(anyhow::Error)` if Docker could not be queried
+    pub async fn image(&self) -> Result<Option<PlatformImage>, anyhow::Error> {
+        let container = match self
+            .docker
+            .inspect_container(self.name.as_str(), None)
+            .await
+        {
+            Ok(container) => container,
+            Err(bollard::errors::Error::DockerResponseServerError {
+                status_code: 404, ..
+            }) => return Ok(None),
+            Err(e) => return Err(anyhow::anyhow!("Failed to inspect container: {}", e)),
+        };
+
+        let id = container.image.unwrap_or_default();
+        let reference = container
+            .config
+            .and_then(|config| config.image)
+            .unwrap_or_else(|| self.image.clone());
+        let digest = self
+            .docker
+            .inspect_image(&id)
+            .await
+            .ok()
+            .and_then(|image| image.repo_digests)
+            .and_then(|digests| digests.into_iter().next())
+            .and_then(|digest| digest.split_once('@').map(|(_, digest)| digest.to_string()));
+
+        Ok(Some(PlatformImage {
+            reference,
+            id,
+            digest,
+        }))
+    }
+
     async fn check_nephelios(&self, filters: HashMap<&str, Vec<&str>>) -> bool {
         let options = Some(ListContainersOptions {
             filters,
@@ -251,10 +337,10 @@ impl NepheliosService {
     /// * `Err(String)` if there was an error during the process
     pub async fn ensure_volumes(&self) -> Result<(), anyhow::Error> {
         for volume in self.volumes.iter() {
-            if !(volume.is_volume_created(&self.docker).await) {
-                if !volume.create_volume(&self.docker).await {
-                    return Err(anyhow::anyhow!("Failed to create volume {}", volume.name));
-                }
+            if !volume.is_volume_created(&self.docker).await
+                && !volume.create_volume(&self.docker).await
+            {
+                return Err(anyhow::anyhow!("Failed to create volume {}", volume.name));
             }
         }
 
@@ -7,10 +7,6 @@ pub struct NepheliosVolume {
 }
 
 impl NepheliosVolume {
-    pub fn new(name: &'static str, mount_path: &'static str) -> Self {
-        Self { name, mount_path }
-    }
-
     pub async fn is_volume_created(&self, docker: &Docker) -> bool {
         // Check if volume exists
         let filters: HashMap<String, Vec<String>> = {
@@ -1,10 +1,19 @@
+mod api;
 mod commands;
 mod docker;
+#[cfg(test)]
+mod tests;
 mod types;
 mod utils;
 
-use crate::types::cli::{Cli, Commands};
+use crate::types::cli::{
+    CertCommands, Cli, Commands, ContextCommands, DomainsCommands, EnvCommands, JobsCommands,
+    PlatformCommands, SecretCommands, TokenCommands, UsersCommands,
+};
+use crate::utils::config::Config;
+use crate::utils::logging;
 use clap::Parser;
+use std::time::Duration;
 
 /// Main entry point for the Nephelios CLI application.
 /// Parses command line arguments and executes the appropriate command.
@@ -16,14 +25,32 @@ use clap::Parser;
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
     let cli = Cli::parse();
+    logging::init(cli.verbose, cli.log_file.as_deref())?;
+    Config::select(cli.context.as_deref(), cli.tls_options());
+    cli.retry_policy().install();
 
     match &cli.command {
         Commands::Create {
             name,
             type_,
+            path,
             github_url,
+            git_token,
+            git_ssh_key,
+            detach,
         } => {
-            commands::create::execute(name, type_, github_url).await?;
+            let context = Config::load()?.active_with_git_token(cli.context.as_deref())?;
+            commands::create::execute(
+                name,
+                *type_,
+                path.as_deref(),
+                github_url,
+                git_token.as_deref(),
+                git_ssh_key.as_deref(),
+                *detach,
+                &context,
+            )
+            .await?;
         }
 
         Commands::Remove { name } => {
@@ -38,6 +65,34 @@ async fn main() -> anyhow::Result<()> {
             commands::start::execute(name).await?;
         }
 
+        Commands::Restart { name, timeout } => {
+            commands::restart::execute(name, Duration::from_secs(*timeout)).await?;
+        }
+
+        Commands::Redeploy { name, git_ref } => {
+            commands::redeploy::execute(name, git_ref.as_deref()).await?;
+        }
+
+        Commands::Releases { name } => {
+            commands::releases::execute(name).await?;
+        }
+
+        Commands::Rollback { name, to } => {
+            commands::rollback::execute(name, *to).await?;
+        }
+
+        Commands::Scale {
+            name,
+            replicas,
+            min,
+            max,
+            no_wait,
+            wait_timeout,
+        } => {
+            let wait_timeout = (!no_wait).then(|| Duration::from_secs(*wait_timeout));
+            commands::scale::execute(name, *replicas, *min, *max, wait_timeout).await?;
+        }
+
         Commands::Up {} => {
             commands::up::execute().await?;
         }
@@ -45,6 +100,169 @@ async fn main() -> anyhow::Result<()> {
         Commands::Down {} => {
             commands::down::execute().await?;
         }
+
+        Commands::Apply { file } => {
+            let context = Config::load()?.active_with_git_token(cli.context.as_deref())?;
+            commands::apply::execute(file, &context).await?;
+        }
+
+        Commands::Diff { file } => {
+            if commands::diff::execute(file).await? {
+                std::process::exit(commands::diff::EXIT_DIFFERENCES);
+            }
+        }
+
+        Commands::Init { file, force } => {
+            let context = Config::load()?.active(cli.context.as_deref());
+            commands::init::execute(file, *force, &context).await?;
+        }
+
+        Commands::Platform { command } => match command {
+            PlatformCommands::Restart { timeout } => {
+                commands::platform::restart(Duration::from_secs(*timeout)).await?
+            }
+        },
+
+        Commands::Env { command } => match command {
+            EnvCommands::List { name, reveal } => commands::env::list(name, *reveal).await?,
+            EnvCommands::Set {
+                name,
+                vars,
+                restart,
+            } => commands::env::set(name, vars, *restart).await?,
+            EnvCommands::Unset {
+                name,
+                keys,
+                restart,
+            } => commands::env::unset(name, keys, *restart).await?,
+            EnvCommands::Import {
+                name,
+                file,
+                restart,
+            } => commands::env::import(name, file, *restart).await?,
+        },
+
+        Commands::Secret { command } => match command {
+            SecretCommands::Create {
+                name,
+                secret,
+                from_file,
+                attach,
+            } => commands::secret::create(name, secret, from_file.as_deref(), *attach).await?,
+            SecretCommands::List { name } => commands::secret::list(name).await?,
+            SecretCommands::Rm { name, secret } => commands::secret::remove(name, secret).await?,
+            SecretCommands::Attach {
+                name,
+                secret,
+                target,
+            } => commands::secret::attach(name, secret, target.as_deref()).await?,
+        },
+
+        Commands::Domains { command } => {
+            let context = Config::load()?.active(cli.context.as_deref());
+            match command {
+                DomainsCommands::Add {
+                    name,
+                    host,
+                    tls,
+                    acme_directory,
+                    acme_email,
+                } => {
+                    commands::domains::add(
+                        name,
+                        host,
+                        *tls,
+                        acme_directory.as_deref(),
+                        acme_email.as_deref(),
+                        &context,
+                    )
+                    .await?
+                }
+                DomainsCommands::List { name } => commands::domains::list(name).await?,
+                DomainsCommands::Rm { name, host } => {
+                    commands::domains::remove(name, host, &context).await?
+                }
+                DomainsCommands::Cert {
+                    command:
+                        CertCommands::Upload {
+                            name,
+                            host,
+                            cert,
+                            key,
+                        },
+                } => commands::domains::upload_certificate(name, host, cert, key, &context).await?,
+            }
+        }
+
+        Commands::Jobs { command } => match command {
+            JobsCommands::List {} => commands::jobs::list().await?,
+            JobsCommands::Wait { job_id, timeout } => {
+                commands::jobs::wait(job_id, timeout.map(Duration::from_secs)).await?
+            }
+            JobsCommands::Cancel { job_id } => commands::jobs::cancel(job_id).await?,
+        },
+
+        Commands::Login {
+            username,
+            password_stdin,
+            device,
+            issuer,
+            client_id,
+        } => {
+            if *device {
+                commands::auth::login_device(issuer.as_deref(), client_id.as_deref()).await?
+            } else {
+                commands::auth::login(username.as_deref(), *password_stdin).await?
+            }
+        }
+        Commands::Logout {} => commands::auth::logout().await?,
+        Commands::Whoami {} => commands::auth::whoami().await?,
+        Commands::Version {} => commands::version::execute().await?,
+        Commands::Token { command } => match command {
+            TokenCommands::Create {
+                scopes,
+                expires,
+                name,
+            } => commands::token::create(name.as_deref(), scopes, *expires).await?,
+            TokenCommands::List {} => commands::token::list().await?,
+            TokenCommands::Revoke { id } => commands::token::revoke(id).await?,
+        },
+
+        Commands::Users { command } => match command {
+            UsersCommands::List { name } => commands::users::list(name.as_deref()).await?,
+            UsersCommands::Invite { email, role } => commands::users::invite(email, *role).await?,
+            UsersCommands::Remove { username } => commands::users::remove(username).await?,
+            UsersCommands::SetRole { username, role } => {
+                commands::users::set_role(username, *role).await?
+            }
+            UsersCommands::Grant {
+                username,
+                name,
+                role,
+            } => commands::users::grant(username, name, *role).await?,
+            UsersCommands::Revoke { username, name } => {
+                commands::users::revoke(username, name).await?
+            }
+        },
+
+        Commands::Context { command } => match command {
+            ContextCommands::List {} => commands::context::list(cli.context.as_deref())?,
+            ContextCommands::Use { name } => commands::context::use_context(name)?,
+            ContextCommands::Set { name, settings } => commands::context::set(
+                name,
+                settings.git_token.as_deref(),
+                settings.git_ssh_key.as_deref(),
+                settings.domain_suffix.as_deref(),
+                settings.acme_directory.as_deref(),
+                settings.acme_email.as_deref(),
+                settings.oidc_issuer.as_deref(),
+                settings.oidc_client_id.as_deref(),
+                settings.proxy.as_deref(),
+                settings.no_proxy.as_deref(),
+                &cli.tls_options(),
+            )?,
+            ContextCommands::Remove { name } => commands::context::remove(name)?,
+        },
     }
 
     Ok(())
@@ -0,0 +1,40 @@
+use crate::types::app_type::AppType;
+use std::fs;
+
+/// Tests that the application type is detected from project files.
+#[test]
+fn test_detect_app_type() {
+    let cases: [(&str, AppType); 5] = [
+        ("package.json", AppType::Nodejs),
+        ("pyproject.toml", AppType::Python),
+        ("requirements.txt", AppType::Python),
+        ("Cargo.toml", AppType::Rust),
+        ("Dockerfile", AppType::Docker),
+    ];
+
+    for (file, expected) in cases {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(dir.path().join(file), "").unwrap();
+        assert_eq!(AppType::detect(dir.path()).unwrap(), expected, "{}", file);
+    }
+}
+
+/// Tests that a Dockerfile does not hide the language of the project.
+#[test]
+fn test_detect_prefers_language_over_dockerfile() {
+    let dir = tempfile::tempdir().unwrap();
+    fs::write(dir.path().join("Dockerfile"), "").unwrap();
+    fs::write(dir.path().join("Cargo.toml"), "").unwrap();
+    assert_eq!(AppType::detect(dir.path()).unwrap(), AppType::Rust);
+}
+
+/// Tests that detection fails on empty or ambiguous projects.
+#[test]
+fn test_detect_app_type_errors() {
+    let dir = tempfile::tempdir().unwrap();
+    assert!(AppType::detect(dir.path()).is_err());
+
+    fs::write(dir.path().join("package.json"), "").unwrap();
+    fs::write(dir.path().join("requirements.txt"), "").unwrap();
+    assert!(AppType::detect(dir.path()).is_err());
+}
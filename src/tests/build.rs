@@ -0,0 +1,78 @@
+use crate::api::build::{parse_build_line, parse_done_message, BuildEvent};
+use crate::utils::build_log::BuildLog;
+use anyhow::anyhow;
+use indicatif::ProgressBar;
+use std::fs;
+use std::path::Path;
+
+/// Tests that NDJSON and SSE lines are parsed into build events.
+#[test]
+fn test_parse_build_line() {
+    assert_eq!(
+        parse_build_line(r#"{"type":"step","name":"Cloning repository"}"#),
+        Some(BuildEvent::Step {
+            name: "Cloning repository".to_string()
+        })
+    );
+    assert_eq!(
+        parse_build_line(r#"data: {"type":"error","message":"npm ci failed"}"#),
+        Some(BuildEvent::Error {
+            message: "npm ci failed".to_string()
+        })
+    );
+    assert_eq!(
+        parse_build_line(r#"{"type":"done"}"#),
+        Some(BuildEvent::Done { message: None })
+    );
+}
+
+/// Tests that plain text is kept as log output and SSE metadata is skipped.
+#[test]
+fn test_parse_build_line_fallbacks() {
+    assert_eq!(
+        parse_build_line("Step 1/8 : FROM node:20-alpine\r"),
+        Some(BuildEvent::Log {
+            line: "Step 1/8 : FROM node:20-alpine".to_string()
+        })
+    );
+    assert_eq!(parse_build_line(""), None);
+    assert_eq!(parse_build_line(": keep-alive"), None);
+    assert_eq!(parse_build_line("event: build"), None);
+}
+
+/// Tests that only the message of a build that was not streamed is shown.
+#[test]
+fn test_parse_done_message() {
+    assert_eq!(
+        parse_done_message(r#"{"message":"App deployed","app_name":"my-app"}"#),
+        Some("App deployed".to_string())
+    );
+    assert_eq!(parse_done_message(r#"{"app_name":"my-app"}"#), None);
+    assert_eq!(parse_done_message("OK"), None);
+    assert_eq!(parse_done_message(""), None);
+}
+
+/// Tests that the saved log is mentioned when the build stream breaks.
+#[test]
+fn test_build_log_saved_on_stream_error() {
+    let dir = tempfile::tempdir().unwrap();
+    let mut log = BuildLog::in_dir("my-app", ProgressBar::hidden(), dir.path().to_path_buf());
+    log.handle(BuildEvent::Step {
+        name: "Cloning repository".to_string(),
+    });
+
+    let error = log
+        .finish(Err(anyhow!("connection reset")))
+        .unwrap_err()
+        .to_string();
+    assert!(error.starts_with("connection reset\n"), "{}", error);
+
+    let path = error
+        .split("Full build log saved to ")
+        .nth(1)
+        .unwrap_or_else(|| panic!("{}", error));
+    assert!(Path::new(path).starts_with(dir.path()), "{}", path);
+    assert!(fs::read_to_string(path)
+        .unwrap()
+        .contains("==> Cloning repository"));
+}
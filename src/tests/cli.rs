@@ -1,4 +1,7 @@
+use crate::types::cli::{parse_duration, Cli};
 use assert_cmd::Command;
+use clap::Parser;
+use std::time::Duration;
 
 /// Tests the CLI help command output.
 ///
@@ -17,3 +20,45 @@ fn test_cli_help() {
         .success()
         .stdout(predicates::str::contains("Nephelios CLI tool"));
 }
+
+/// Tests the parsing of token lifetimes such as `90d`.
+#[test]
+fn test_parse_duration() {
+    assert_eq!(parse_duration("90d"), Ok(Duration::from_secs(90 * 86400)));
+    assert_eq!(parse_duration("12h"), Ok(Duration::from_secs(12 * 3600)));
+    assert_eq!(parse_duration("1w"), Ok(Duration::from_secs(7 * 86400)));
+    for invalid in [
+        "",
+        "90",
+        "d",
+        "0d",
+        "90y",
+        "-1d",
+        "1.5h",
+        "99999999999999999d",
+    ] {
+        assert!(parse_duration(invalid).is_err(), "{}", invalid);
+    }
+}
+
+/// Tests that request settings can follow the subcommand, and do not clash with
+/// the wait timeouts of some subcommands.
+#[test]
+fn test_request_settings_are_global() {
+    let cli =
+        Cli::try_parse_from(["nephelios-cli", "start", "--name", "x", "--retries", "0"]).unwrap();
+    assert_eq!(cli.retry_policy().retries, 0);
+
+    let cli = Cli::try_parse_from([
+        "nephelios-cli",
+        "restart",
+        "--name",
+        "x",
+        "--timeout",
+        "600",
+        "--request-timeout",
+        "5",
+    ])
+    .unwrap();
+    assert_eq!(cli.retry_policy().timeout, Duration::from_secs(5));
+}
@@ -0,0 +1,25 @@
+use crate::utils::config::write_private;
+use std::fs;
+use std::os::unix::fs::PermissionsExt;
+
+/// Tests that files holding credentials are only readable by the current user,
+/// including files that existed with looser permissions.
+#[test]
+fn test_write_private() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("credentials.toml");
+
+    write_private(&path, "token = \"a\"").unwrap();
+    assert_eq!(
+        fs::metadata(&path).unwrap().permissions().mode() & 0o777,
+        0o600
+    );
+
+    fs::set_permissions(&path, fs::Permissions::from_mode(0o644)).unwrap();
+    write_private(&path, "token = \"b\"").unwrap();
+    assert_eq!(
+        fs::metadata(&path).unwrap().permissions().mode() & 0o777,
+        0o600
+    );
+    assert_eq!(fs::read_to_string(&path).unwrap(), "token = \"b\"");
+}
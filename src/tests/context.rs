@@ -0,0 +1,38 @@
+use assert_cmd::Command;
+use std::fs;
+
+/// Tests that removing a context also forgets the credentials it was logged in with.
+#[test]
+fn test_remove_context_deletes_credentials() {
+    let dir = tempfile::tempdir().unwrap();
+    let config = dir.path().join("config.toml");
+    let credentials = dir.path().join("credentials.toml");
+    fs::write(&config, "[contexts.staging]\n\n[contexts.prod]\n").unwrap();
+    fs::write(
+        &credentials,
+        "[sessions.staging]\n\
+         token = \"staging-token\"\n\
+         refresh_token = \"staging-refresh\"\n\
+         \n\
+         [sessions.prod]\n\
+         token = \"prod-token\"\n\
+         \n\
+         [git_tokens]\n\
+         staging = \"staging-git\"\n",
+    )
+    .unwrap();
+
+    Command::cargo_bin("nephelios-cli")
+        .unwrap()
+        .current_dir(dir.path())
+        .env("NEPHELIOS_CONFIG", &config)
+        .args(["context", "remove", "staging"])
+        .assert()
+        .success();
+
+    let remaining = fs::read_to_string(&credentials).unwrap();
+    for secret in ["staging-token", "staging-refresh", "staging-git"] {
+        assert!(!remaining.contains(secret), "{}", remaining);
+    }
+    assert!(remaining.contains("prod-token"), "{}", remaining);
+}
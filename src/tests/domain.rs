@@ -0,0 +1,60 @@
+use crate::api::domains::Domain;
+use crate::utils::domain::{normalize_suffix, qualify_host};
+
+/// Tests that single labels are completed with the context's domain suffix.
+#[test]
+fn test_qualify_host() {
+    assert_eq!(
+        qualify_host("API", Some("apps.example.com")).unwrap(),
+        "api.apps.example.com"
+    );
+    assert_eq!(
+        qualify_host("my-app.example.org.", Some("apps.example.com")).unwrap(),
+        "my-app.example.org"
+    );
+    assert!(qualify_host("api", None).is_err());
+}
+
+/// Tests that domain suffixes may be a single label, like the default `localhost`.
+#[test]
+fn test_normalize_suffix() {
+    assert_eq!(normalize_suffix("localhost").unwrap(), "localhost");
+    assert_eq!(
+        normalize_suffix(".Apps.Example.com.").unwrap(),
+        "apps.example.com"
+    );
+    assert_eq!(
+        qualify_host("api", Some(&normalize_suffix("localhost").unwrap())).unwrap(),
+        "api.localhost"
+    );
+    for suffix in ["", "-apps.example.com", "apps..example.com", "my_apps"] {
+        assert!(normalize_suffix(suffix).is_err(), "{}", suffix);
+    }
+}
+
+/// Tests that invalid host names are rejected.
+#[test]
+fn test_qualify_invalid_host() {
+    for host in ["-api.example.com", "api..example.com", "my_app.example.com"] {
+        assert!(qualify_host(host, None).is_err(), "{}", host);
+    }
+}
+
+/// Tests that certificate status and expiry are reported for domains that have one.
+#[test]
+fn test_certificate_summary() {
+    let domains: Vec<Domain> = serde_json::from_str(
+        r#"[
+            {"host":"a.example.com","url":"http://a.example.com"},
+            {"host":"b.example.com","url":"https://b.example.com",
+             "certificate":{"status":"issued","source":"acme","expires_at":"2026-01-01"}}
+        ]"#,
+    )
+    .unwrap();
+
+    assert!(domains[0].certificate.is_none());
+    assert_eq!(
+        domains[1].certificate.as_ref().unwrap().summary(),
+        "issued by ACME, expires 2026-01-01"
+    );
+}
@@ -0,0 +1,63 @@
+use crate::utils::env_file::read_env_file;
+use std::fs;
+
+/// Tests that .env files are parsed with comments, exports and quotes.
+#[test]
+fn test_read_env_file() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join(".env");
+    fs::write(
+        &path,
+        "# Database\n\
+         DATABASE_URL=postgres://db/app\n\
+         \n\
+         export NODE_ENV=production\n\
+         GREETING=\"hello world\"\n\
+         TOKEN='a=b'\n\
+         EMPTY=\n",
+    )
+    .unwrap();
+
+    let vars = read_env_file(&path).unwrap();
+    assert_eq!(vars.len(), 5);
+    assert_eq!(vars["DATABASE_URL"], "postgres://db/app");
+    assert_eq!(vars["NODE_ENV"], "production");
+    assert_eq!(vars["GREETING"], "hello world");
+    assert_eq!(vars["TOKEN"], "a=b");
+    assert_eq!(vars["EMPTY"], "");
+}
+
+/// Tests that malformed lines and missing files are rejected.
+#[test]
+fn test_read_env_file_errors() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join(".env");
+
+    for invalid in ["NOT_A_PAIR", "=value", "MY KEY=value", "A=\"unterminated"] {
+        fs::write(&path, invalid).unwrap();
+        assert!(read_env_file(&path).is_err(), "{}", invalid);
+    }
+
+    assert!(read_env_file(&dir.path().join("missing.env")).is_err());
+}
+
+/// Tests that values are taken literally, without expanding the local environment.
+#[test]
+fn test_read_env_file_keeps_references() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join(".env");
+    fs::write(
+        &path,
+        "DATA_DIR=${HOME}/data\n\
+         QUOTED=\"$HOME\"\n\
+         LITERAL='${PATH}' # comment\n\
+         ESCAPED=\"a\\\"b\"\n",
+    )
+    .unwrap();
+
+    let vars = read_env_file(&path).unwrap();
+    assert_eq!(vars["DATA_DIR"], "${HOME}/data");
+    assert_eq!(vars["QUOTED"], "$HOME");
+    assert_eq!(vars["LITERAL"], "${PATH}");
+    assert_eq!(vars["ESCAPED"], "a\"b");
+}
@@ -0,0 +1,48 @@
+use crate::utils::config::ContextConfig;
+use crate::utils::git::{ensure_secure_transport, parse_repo_url, GitCredentials, RepoTransport};
+
+/// Tests that HTTPS and SSH repository URLs are recognised.
+#[test]
+fn test_parse_repo_url() {
+    assert_eq!(
+        parse_repo_url("https://github.com/Nephelios/nephelios-cli").unwrap(),
+        RepoTransport::Https
+    );
+    assert_eq!(
+        parse_repo_url("git@github.com:Nephelios/nephelios-cli.git").unwrap(),
+        RepoTransport::Ssh
+    );
+    assert_eq!(
+        parse_repo_url("ssh://git@github.com/Nephelios/nephelios-cli.git").unwrap(),
+        RepoTransport::Ssh
+    );
+    assert!(parse_repo_url("github.com/Nephelios/nephelios-cli").is_err());
+    assert!(parse_repo_url("https://github.com/Nephelios").is_err());
+}
+
+/// Tests that credentials flags must match the repository transport.
+#[test]
+fn test_resolve_credentials() {
+    let context = ContextConfig {
+        git_token: Some("stored".to_string()),
+        ..Default::default()
+    };
+
+    let credentials = GitCredentials::resolve(RepoTransport::Https, None, None, &context).unwrap();
+    assert_eq!(credentials.token.as_deref(), Some("stored"));
+
+    let credentials = GitCredentials::resolve(RepoTransport::Ssh, None, None, &context).unwrap();
+    assert!(credentials.is_empty());
+
+    assert!(GitCredentials::resolve(RepoTransport::Ssh, Some("token"), None, &context).is_err());
+}
+
+/// Tests that credentials are only sent in clear text to loopback addresses.
+#[test]
+fn test_ensure_secure_transport() {
+    assert!(ensure_secure_transport("https://nephelios.example.com").is_ok());
+    assert!(ensure_secure_transport("http://localhost").is_ok());
+    assert!(ensure_secure_transport("http://127.0.0.1:3030").is_ok());
+    assert!(ensure_secure_transport("http://[::1]").is_ok());
+    assert!(ensure_secure_transport("http://nephelios.example.com").is_err());
+}
@@ -0,0 +1,29 @@
+use crate::api::jobs::Job;
+
+/// Tests that a job reported by the server is parsed with optional fields missing.
+#[test]
+fn test_parse_job() {
+    let job: Job = serde_json::from_str(
+        r#"{"id":"42","kind":"create","app_name":"my-app","status":"running","progress":40}"#,
+    )
+    .unwrap();
+    assert_eq!(job.progress, Some(40));
+    assert_eq!(job.step, None);
+    assert!(!job.is_finished());
+}
+
+/// Tests which statuses end a job.
+#[test]
+fn test_job_is_finished() {
+    for (status, finished) in [
+        ("queued", false),
+        ("running", false),
+        ("succeeded", true),
+        ("failed", true),
+        ("cancelled", true),
+    ] {
+        let job: Job =
+            serde_json::from_str(&format!(r#"{{"id":"1","status":"{}"}}"#, status)).unwrap();
+        assert_eq!(job.is_finished(), finished, "{}", status);
+    }
+}
@@ -0,0 +1,165 @@
+use crate::types::app_spec::AppSpec;
+use crate::types::manifest::Manifest;
+use assert_cmd::Command;
+use std::fs;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+const MANIFEST: &str = r#"
+name = "my-app"
+type = "nodejs"
+replicas = 2
+domains = ["my-app.example.com"]
+
+[source]
+github_url = "https://github.com/user/repo"
+ref = "main"
+
+[env]
+NODE_ENV = "production"
+
+[resources]
+memory = "512M"
+"#;
+
+/// Tests that a manifest is turned into the desired application state.
+#[test]
+fn test_manifest_to_spec() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("nephelios.toml");
+    fs::write(&path, MANIFEST).unwrap();
+
+    let spec = Manifest::load(&path).unwrap().to_spec(&path).unwrap();
+    assert_eq!(spec.app_name, "my-app");
+    assert_eq!(spec.app_type, "nodejs");
+    assert_eq!(spec.git_ref.as_deref(), Some("main"));
+    assert_eq!(spec.replicas, 2);
+    assert_eq!(spec.env["NODE_ENV"], "production");
+    assert_eq!(spec.resources.memory.as_deref(), Some("512M"));
+}
+
+/// Tests that unknown fields and invalid values are rejected.
+#[test]
+fn test_manifest_validation() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("nephelios.toml");
+
+    fs::write(&path, MANIFEST.replace("replicas = 2", "replica = 2")).unwrap();
+    assert!(Manifest::load(&path).is_err());
+
+    fs::write(&path, MANIFEST.replace("replicas = 2", "replicas = 0")).unwrap();
+    assert!(Manifest::load(&path).is_err());
+
+    fs::write(&path, MANIFEST.replace("\"nodejs\"", "\"node\"")).unwrap();
+    assert!(Manifest::load(&path).is_err());
+}
+
+/// Tests that only the differing fields are reported as changes.
+#[test]
+fn test_spec_changes() {
+    let live = AppSpec {
+        app_name: "my-app".to_string(),
+        app_type: "nodejs".to_string(),
+        github_url: "https://github.com/user/repo".to_string(),
+        replicas: 1,
+        env: [("A".to_string(), "1".to_string())].into(),
+        ..Default::default()
+    };
+    assert!(live.changes(&live).is_empty());
+
+    let mut desired = live.clone();
+    desired.replicas = 3;
+    desired.env.insert("A".to_string(), "secret".to_string());
+    desired.env.insert("B".to_string(), "2".to_string());
+    desired.domains.push("my-app.example.com".to_string());
+
+    let changes: Vec<String> = live
+        .changes(&desired)
+        .iter()
+        .map(ToString::to_string)
+        .collect();
+    assert_eq!(
+        changes,
+        vec![
+            "~ replicas: 1 -> 3",
+            "~ env.A: changed",
+            "+ env.B: ********",
+            "+ domains: my-app.example.com",
+        ]
+    );
+}
+
+/// Tests that a new application is previewed as a list of additions.
+#[test]
+fn test_spec_creation_changes() {
+    let desired = AppSpec {
+        app_name: "my-app".to_string(),
+        app_type: "rust".to_string(),
+        github_url: "https://github.com/user/repo".to_string(),
+        replicas: 1,
+        ..Default::default()
+    };
+
+    let changes: Vec<String> = desired
+        .creation_changes()
+        .iter()
+        .map(ToString::to_string)
+        .collect();
+    assert_eq!(
+        changes,
+        vec![
+            "+ type: rust",
+            "+ github_url: https://github.com/user/repo",
+            "+ replicas: 1",
+        ]
+    );
+}
+
+/// Tests that a saved manifest can be loaded back.
+#[test]
+fn test_manifest_save_roundtrip() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("nephelios.toml");
+    fs::write(&path, MANIFEST).unwrap();
+
+    let manifest = Manifest::load(&path).unwrap();
+    let copy = dir.path().join("copy.toml");
+    manifest.save(&copy).unwrap();
+
+    assert_eq!(
+        Manifest::load(&copy).unwrap().to_spec(&copy).unwrap(),
+        manifest.to_spec(&path).unwrap()
+    );
+}
+
+/// Tests that `apply` fails when the server refuses the request, so CI can detect it.
+#[tokio::test(flavor = "multi_thread")]
+async fn test_apply_fails_on_error_status() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/apps/my-app"))
+        .respond_with(ResponseTemplate::new(404))
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/create"))
+        .respond_with(ResponseTemplate::new(403).set_body_string(r#"{"required_role":"deployer"}"#))
+        .mount(&server)
+        .await;
+
+    let dir = tempfile::tempdir().unwrap();
+    let manifest = dir.path().join("nephelios.toml");
+    fs::write(&manifest, MANIFEST).unwrap();
+
+    Command::cargo_bin("nephelios-cli")
+        .unwrap()
+        .current_dir(dir.path())
+        .env("NEPHELIOS_URL", server.uri())
+        .env("NEPHELIOS_CONFIG", dir.path().join("config.toml"))
+        .env_remove("NEPHELIOS_TOKEN")
+        .args(["apply", "-f"])
+        .arg(&manifest)
+        .assert()
+        .failure()
+        .stderr(predicates::str::contains("requires the 'deployer' role"));
+}
@@ -0,0 +1,37 @@
+mod app_type;
+mod build;
+mod cli;
+mod config;
+mod context;
+mod domain;
+mod env_file;
+mod git;
+mod jobs;
+mod logging;
+mod manifest;
+mod oauth;
+mod permissions;
+mod releases;
+mod retry;
+mod rollout;
+mod secrets;
+mod server_url;
+mod tls;
+mod transport;
+mod version;
+
+use crate::api::client::NepheliosClient;
+use crate::utils::config::ContextConfig;
+use url::Url;
+use wiremock::MockServer;
+
+/// Creates a client of the API served by a mock server, without credentials.
+fn test_client(server: &MockServer) -> NepheliosClient {
+    test_client_at(&server.uri())
+}
+
+/// Creates a client of the API served at `uri`, without credentials.
+fn test_client_at(uri: &str) -> NepheliosClient {
+    let base_url = Url::parse(&format!("{}/", uri)).unwrap();
+    NepheliosClient::for_server(base_url, ContextConfig::default(), None).unwrap()
+}
@@ -0,0 +1,225 @@
+use crate::api::oauth::OAuthProvider;
+use assert_cmd::Command;
+use reqwest::Client;
+use serde_json::json;
+use std::fs;
+use std::path::Path;
+use uuid::Uuid;
+use wiremock::matchers::{body_string_contains, header, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+/// Starts a stub identity provider advertising its device and token endpoints.
+async fn identity_provider() -> MockServer {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/.well-known/openid-configuration"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "issuer": server.uri(),
+            "device_authorization_endpoint": format!("{}/device", server.uri()),
+            "token_endpoint": format!("{}/token", server.uri()),
+        })))
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/device"))
+        .and(body_string_contains("client_id=nephelios-cli"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "device_code": "device-123",
+            "user_code": "ABCD-EFGH",
+            "verification_uri": "https://sso.example.com/device",
+            "expires_in": 60,
+            "interval": 0,
+        })))
+        .mount(&server)
+        .await;
+    server
+}
+
+fn token_error(error: &str) -> ResponseTemplate {
+    ResponseTemplate::new(400).set_body_json(json!({ "error": error }))
+}
+
+/// Tests that the device login polls until the user approves it.
+#[tokio::test]
+async fn test_device_login() {
+    let server = identity_provider().await;
+    Mock::given(method("POST"))
+        .and(path("/token"))
+        .respond_with(token_error("authorization_pending"))
+        .up_to_n_times(2)
+        .with_priority(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/token"))
+        .and(body_string_contains("device_code=device-123"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "access_token": "access-1",
+            "refresh_token": "refresh-1",
+            "token_type": "Bearer",
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let provider = OAuthProvider::discover(Client::new(), &server.uri(), "nephelios-cli")
+        .await
+        .unwrap();
+    let device = provider.authorize_device().await.unwrap();
+    assert_eq!(device.user_code, "ABCD-EFGH");
+
+    let tokens = provider.wait_for_token(&device).await.unwrap();
+    assert_eq!(tokens.access_token, "access-1");
+    assert_eq!(tokens.refresh_token.as_deref(), Some("refresh-1"));
+}
+
+/// Tests that a denied device login stops polling with an error.
+#[tokio::test]
+async fn test_device_login_denied() {
+    let server = identity_provider().await;
+    Mock::given(method("POST"))
+        .and(path("/token"))
+        .respond_with(token_error("access_denied"))
+        .mount(&server)
+        .await;
+
+    let provider = OAuthProvider::discover(Client::new(), &server.uri(), "nephelios-cli")
+        .await
+        .unwrap();
+    let device = provider.authorize_device().await.unwrap();
+    let error = provider.wait_for_token(&device).await.unwrap_err();
+    assert!(error.to_string().contains("denied"));
+}
+
+/// Tests that a refresh token is exchanged for a new access token.
+#[tokio::test]
+async fn test_refresh_token() {
+    let server = identity_provider().await;
+    Mock::given(method("POST"))
+        .and(path("/token"))
+        .and(body_string_contains("grant_type=refresh_token"))
+        .and(body_string_contains("refresh_token=refresh-1"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "access_token": "access-2",
+        })))
+        .mount(&server)
+        .await;
+
+    let provider = OAuthProvider::discover(Client::new(), &server.uri(), "nephelios-cli")
+        .await
+        .unwrap();
+    let tokens = provider.refresh("refresh-1").await.unwrap();
+    assert_eq!(tokens.access_token, "access-2");
+    assert_eq!(tokens.refresh_token, None);
+}
+
+/// Writes a context logged in through `server` with an expired access token,
+/// and returns its name.
+fn expired_session(dir: &Path, server: &MockServer) -> String {
+    // A unique name, so that the test never touches a real session in the keyring
+    let context = format!("refresh-{}", Uuid::new_v4());
+    fs::write(
+        dir.join("config.toml"),
+        format!(
+            "[contexts.{}]\noidc_issuer = \"{}\"\noidc_client_id = \"nephelios-cli\"\n",
+            context,
+            server.uri()
+        ),
+    )
+    .unwrap();
+    fs::write(
+        dir.join("credentials.toml"),
+        format!(
+            "[sessions.{}]\ntoken = \"access-1\"\nrefresh_token = \"refresh-1\"\n",
+            context
+        ),
+    )
+    .unwrap();
+    context
+}
+
+fn cli(dir: &Path, server: &MockServer, context: &str) -> Command {
+    let mut command = Command::cargo_bin("nephelios-cli").unwrap();
+    command
+        .current_dir(dir)
+        .env("NEPHELIOS_URL", server.uri())
+        .env("NEPHELIOS_CONFIG", dir.join("config.toml"))
+        .env_remove("NEPHELIOS_TOKEN")
+        .args(["--context", context]);
+    command
+}
+
+/// Tests that a request refused with 401 refreshes the session, is sent again
+/// with the new token, and that the new session is kept for the next commands.
+#[tokio::test]
+async fn test_expired_session_is_refreshed() {
+    let server = identity_provider().await;
+    Mock::given(method("POST"))
+        .and(path("/token"))
+        .and(body_string_contains("refresh_token=refresh-1"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "access_token": "access-2",
+            "refresh_token": "refresh-2",
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/jobs"))
+        .and(header("authorization", "Bearer access-1"))
+        .respond_with(ResponseTemplate::new(401))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/jobs"))
+        .and(header("authorization", "Bearer access-2"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([])))
+        .expect(2)
+        .mount(&server)
+        .await;
+
+    let dir = tempfile::tempdir().unwrap();
+    let context = expired_session(dir.path(), &server);
+    cli(dir.path(), &server, &context)
+        .args(["jobs", "list"])
+        .assert()
+        .success();
+    // The refreshed session was saved, so it is used without refreshing again
+    cli(dir.path(), &server, &context)
+        .args(["jobs", "list"])
+        .assert()
+        .success();
+
+    cli(dir.path(), &server, &context)
+        .args(["context", "remove", &context])
+        .assert()
+        .success();
+}
+
+/// Tests that a session which cannot be refreshed asks the user to log in again.
+#[tokio::test]
+async fn test_expired_session_refresh_denied() {
+    let server = identity_provider().await;
+    Mock::given(method("POST"))
+        .and(path("/token"))
+        .respond_with(token_error("invalid_grant"))
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/jobs"))
+        .respond_with(ResponseTemplate::new(401))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let dir = tempfile::tempdir().unwrap();
+    let context = expired_session(dir.path(), &server);
+    cli(dir.path(), &server, &context)
+        .args(["jobs", "list"])
+        .assert()
+        .failure()
+        .stderr(predicates::str::contains(
+            "Run `nephelios-cli login --device` again",
+        ));
+}
@@ -0,0 +1,56 @@
+use crate::api::client::permission_denied;
+use assert_cmd::Command;
+use wiremock::matchers::method;
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+/// Tests that a 403 response names the platform or application role that is missing.
+#[test]
+fn test_permission_denied_names_role() {
+    let message = permission_denied("remove shop", r#"{"required_role":"admin"}"#);
+    assert!(message.contains("requires the 'admin' role"));
+    assert!(message.contains("users set-role"));
+
+    let message = permission_denied(
+        "redeploy shop",
+        r#"{"required_role":"deployer","app_name":"shop"}"#,
+    );
+    assert!(message.contains("requires the 'deployer' role on shop"));
+    assert!(message.contains("users grant <username> --name shop --role deployer"));
+}
+
+/// Tests that a 403 response without a role falls back to the server message.
+#[test]
+fn test_permission_denied_without_role() {
+    let message = permission_denied("remove shop", "forbidden");
+    assert!(message.contains("permission denied"));
+    assert!(message.contains("forbidden"));
+}
+
+/// Tests that app commands exit with an error when the user lacks a role.
+#[tokio::test(flavor = "multi_thread")]
+async fn test_permission_denied_exit_status() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .respond_with(
+            ResponseTemplate::new(403)
+                .set_body_string(r#"{"required_role":"deployer","app_name":"shop"}"#),
+        )
+        .mount(&server)
+        .await;
+
+    let dir = tempfile::tempdir().unwrap();
+    for command in ["start", "stop", "remove"] {
+        Command::cargo_bin("nephelios-cli")
+            .unwrap()
+            .current_dir(dir.path())
+            .env("NEPHELIOS_URL", server.uri())
+            .env("NEPHELIOS_CONFIG", dir.path().join("config.toml"))
+            .env_remove("NEPHELIOS_TOKEN")
+            .args([command, "--name", "shop"])
+            .assert()
+            .failure()
+            .stderr(predicates::str::contains(
+                "requires the 'deployer' role on shop",
+            ));
+    }
+}
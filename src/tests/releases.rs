@@ -0,0 +1,44 @@
+use crate::api::releases::Release;
+use crate::commands::rollback::select_target;
+
+fn release(number: u32, status: &str) -> Release {
+    Release {
+        number,
+        commit: format!("{:040}", number),
+        created_at: String::new(),
+        image_digest: format!("sha256:{}", number),
+        status: status.to_string(),
+    }
+}
+
+/// Tests that the default rollback target skips failed releases.
+#[test]
+fn test_select_default_target() {
+    let releases = vec![
+        release(4, "active"),
+        release(3, "failed"),
+        release(2, "superseded"),
+        release(1, "superseded"),
+    ];
+    assert_eq!(select_target(&releases, None).unwrap().number, 2);
+}
+
+/// Tests that an explicit target must be a previous, successful release.
+#[test]
+fn test_select_explicit_target() {
+    let releases = vec![
+        release(3, "active"),
+        release(2, "failed"),
+        release(1, "superseded"),
+    ];
+    assert_eq!(select_target(&releases, Some(1)).unwrap().number, 1);
+    assert!(select_target(&releases, Some(2)).is_err());
+    assert!(select_target(&releases, Some(3)).is_err());
+    assert!(select_target(&releases, Some(9)).is_err());
+}
+
+/// Tests that a single release cannot be rolled back.
+#[test]
+fn test_select_without_previous_release() {
+    assert!(select_target(&[release(1, "active")], None).is_err());
+}
@@ -0,0 +1,74 @@
+use crate::api::retry::{parse_retry_after, RetryPolicy};
+use crate::tests::test_client;
+use serde_json::json;
+use std::time::{Duration, SystemTime};
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+/// Tests that the backoff grows with each retry, stays jittered and is capped.
+#[test]
+fn test_backoff() {
+    let policy = RetryPolicy::default();
+    for (attempt, max) in [(0, 500), (1, 1000), (2, 2000), (10, 10_000)] {
+        let delay = policy.backoff(attempt);
+        assert!(delay >= Duration::from_millis(max / 2), "{:?}", delay);
+        assert!(delay <= Duration::from_millis(max), "{:?}", delay);
+    }
+}
+
+/// Tests both forms of the `Retry-After` header.
+#[test]
+fn test_parse_retry_after() {
+    let now = SystemTime::UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+    assert_eq!(parse_retry_after("5", now), Some(Duration::from_secs(5)));
+    assert_eq!(
+        parse_retry_after(&httpdate::fmt_http_date(now + Duration::from_secs(20)), now),
+        Some(Duration::from_secs(20))
+    );
+    assert_eq!(
+        parse_retry_after(&httpdate::fmt_http_date(now - Duration::from_secs(20)), now),
+        Some(Duration::ZERO)
+    );
+    assert_eq!(
+        parse_retry_after("3600", now),
+        Some(Duration::from_secs(60))
+    );
+    assert_eq!(parse_retry_after("soon", now), None);
+}
+
+/// Tests that an idempotent request is retried after a 503 response.
+#[tokio::test]
+async fn test_retry_unavailable() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/apps/my-app/status"))
+        .respond_with(ResponseTemplate::new(503).insert_header("Retry-After", "0"))
+        .up_to_n_times(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/apps/my-app/status"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(json!({ "replicas": 2, "running": 2 })),
+        )
+        .mount(&server)
+        .await;
+
+    let status = test_client(&server).app_status("my-app").await.unwrap();
+    assert!(status.is_converged());
+    assert_eq!(server.received_requests().await.unwrap().len(), 2);
+}
+
+/// Tests that a request which may have had an effect is not sent twice.
+#[tokio::test]
+async fn test_no_retry_non_idempotent() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/restart"))
+        .respond_with(ResponseTemplate::new(503).insert_header("Retry-After", "0"))
+        .mount(&server)
+        .await;
+
+    assert!(test_client(&server).restart_app("my-app").await.is_err());
+    assert_eq!(server.received_requests().await.unwrap().len(), 1);
+}
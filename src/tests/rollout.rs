@@ -0,0 +1,79 @@
+use crate::api::apps::{AppStatus, UpdateState};
+use crate::tests::test_client;
+use crate::utils::rollout::wait_for_rollout;
+use serde_json::json;
+use std::time::Duration;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+fn status(value: serde_json::Value) -> AppStatus {
+    serde_json::from_value(value).unwrap()
+}
+
+/// Tests that replicas of the previous spec do not count as rolled out.
+#[test]
+fn test_is_rolled_out() {
+    // Before the rolling update starts, the old replicas are all running
+    let before = status(json!({ "replicas": 3, "running": 3, "version": 7, "updated": 3 }));
+    assert!(before.is_converged());
+    assert!(!before.is_rolled_out(Some(7)));
+
+    let updating = status(json!({
+        "replicas": 3, "running": 3, "version": 8, "updated": 1, "update_state": "updating"
+    }));
+    assert!(!updating.is_rolled_out(Some(7)));
+    assert!(!updating.is_update_aborted());
+
+    let completed = status(json!({
+        "replicas": 3, "running": 3, "version": 8, "updated": 3, "update_state": "completed"
+    }));
+    assert!(completed.is_rolled_out(Some(7)));
+
+    // Servers that do not report the updated replicas do not block the wait forever
+    let unreported = status(json!({
+        "replicas": 3, "running": 3, "version": 8, "update_state": "completed"
+    }));
+    assert!(unreported.is_rolled_out(Some(7)));
+    let starting = status(json!({ "replicas": 3, "running": 1, "version": 8 }));
+    assert!(!starting.is_rolled_out(Some(7)));
+}
+
+/// Tests that paused and rolled back updates are reported as aborted.
+#[test]
+fn test_is_update_aborted() {
+    for state in ["paused", "rollback_started", "rollback_completed"] {
+        let aborted = status(json!({ "replicas": 1, "update_state": state }));
+        assert!(aborted.is_update_aborted(), "{}", state);
+        assert!(!aborted.is_rolled_out(None), "{}", state);
+    }
+
+    let unknown = status(json!({ "replicas": 1, "update_state": "something_new" }));
+    assert_eq!(unknown.update_state, Some(UpdateState::Unknown));
+}
+
+/// Tests that waiting for a rollout fails as soon as the swarm rolls the update back.
+#[tokio::test]
+async fn test_wait_for_rollout_aborted() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/apps/my-app/status"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "replicas": 2,
+            "running": 2,
+            "version": 8,
+            "updated": 0,
+            "update_state": "rollback_completed",
+            "update_message": "update rolled back due to failure or early termination of task",
+        })))
+        .mount(&server)
+        .await;
+
+    let client = test_client(&server);
+    let error = wait_for_rollout(&client, "my-app", Some(7), Duration::from_secs(30))
+        .await
+        .unwrap_err()
+        .to_string();
+
+    assert!(error.contains("my-app was rolled back"), "{}", error);
+    assert!(error.contains("rolled back due to failure"), "{}", error);
+}
@@ -0,0 +1,125 @@
+use crate::api::secrets::validate_secret_name;
+use crate::commands::secret::strip_trailing_newline;
+use crate::tests::test_client;
+use crate::utils::logging::redact_body;
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use serde_json::{json, Value};
+use wiremock::matchers::{body_json, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+/// Tests that secret names must be usable as file names under /run/secrets.
+#[test]
+fn test_validate_secret_name() {
+    for valid in ["DB_PASSWORD", "tls.key", "api-token", "a"] {
+        assert!(validate_secret_name(valid).is_ok(), "{}", valid);
+    }
+    for invalid in ["", ".env", "db/password", "DB PASSWORD", &"a".repeat(65)] {
+        assert!(validate_secret_name(invalid).is_err(), "{}", invalid);
+    }
+}
+
+/// Tests that the line ending of a value piped with `echo` is not stored.
+#[test]
+fn test_strip_trailing_newline() {
+    assert_eq!(strip_trailing_newline(b"pw\n".to_vec()), b"pw");
+    assert_eq!(strip_trailing_newline(b"pw\r\n".to_vec()), b"pw");
+    assert_eq!(strip_trailing_newline(b"pw\n\n".to_vec()), b"pw\n");
+    assert_eq!(strip_trailing_newline(b"pw".to_vec()), b"pw");
+}
+
+/// Tests that a secret is sent to the application's secrets, and hidden from the logs.
+#[tokio::test]
+async fn test_create_secret() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/apps/my-app/secrets"))
+        .respond_with(ResponseTemplate::new(201))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = test_client(&server);
+    client
+        .create_secret("my-app", "DB_PASSWORD", b"hunter2")
+        .await
+        .unwrap();
+
+    let requests = server.received_requests().await.unwrap();
+    let body: Value = serde_json::from_slice(&requests[0].body).unwrap();
+    assert_eq!(body["name"], "DB_PASSWORD");
+    let value = STANDARD
+        .decode(body["secret_value"].as_str().unwrap())
+        .unwrap();
+    assert_eq!(value, b"hunter2");
+
+    let logged = redact_body(&requests[0].body);
+    assert!(logged.contains("DB_PASSWORD"), "{}", logged);
+    assert!(
+        !logged.contains(body["secret_value"].as_str().unwrap()),
+        "{}",
+        logged
+    );
+}
+
+/// Tests that attaching a secret mounts it under its own name unless a target is given.
+#[tokio::test]
+async fn test_attach_secret() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/apps/my-app/secrets/DB_PASSWORD/attach"))
+        .and(body_json(json!({ "target": "DB_PASSWORD" })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "attached": false })))
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/apps/my-app/secrets/DB_PASSWORD/attach"))
+        .and(body_json(json!({ "target": "db" })))
+        .respond_with(ResponseTemplate::new(200))
+        .mount(&server)
+        .await;
+
+    let client = test_client(&server);
+    assert!(!client
+        .attach_secret("my-app", "DB_PASSWORD", None)
+        .await
+        .unwrap());
+    assert!(client
+        .attach_secret("my-app", "DB_PASSWORD", Some("db"))
+        .await
+        .unwrap());
+}
+
+/// Tests that removing a secret goes through the server, which enforces permissions.
+#[tokio::test]
+async fn test_remove_secret() {
+    let server = MockServer::start().await;
+    Mock::given(method("DELETE"))
+        .and(path("/apps/my-app/secrets/DB_PASSWORD"))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("DELETE"))
+        .and(path("/apps/shop/secrets/DB_PASSWORD"))
+        .respond_with(
+            ResponseTemplate::new(403)
+                .set_body_json(json!({ "required_role": "admin", "app_name": "shop" })),
+        )
+        .mount(&server)
+        .await;
+
+    let client = test_client(&server);
+    client.remove_secret("my-app", "DB_PASSWORD").await.unwrap();
+
+    let error = client
+        .remove_secret("shop", "DB_PASSWORD")
+        .await
+        .unwrap_err()
+        .to_string();
+    assert!(
+        error.contains("requires the 'admin' role on shop"),
+        "{}",
+        error
+    );
+}
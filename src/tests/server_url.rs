@@ -0,0 +1,121 @@
+use crate::utils::server_url::{endpoint_url, parse_server_url};
+
+/// Tests how `NEPHELIOS_URL` and `NEPHELIOS_PORT` combine into the API base URL.
+#[test]
+fn test_parse_server_url() {
+    let cases = [
+        (None, None, "http://localhost:3030/"),
+        (
+            Some("http://127.0.0.1"),
+            Some("3030"),
+            "http://127.0.0.1:3030/",
+        ),
+        (
+            Some("http://127.0.0.1:8080/"),
+            None,
+            "http://127.0.0.1:8080/",
+        ),
+        (
+            Some("http://127.0.0.1:8080"),
+            Some("8080"),
+            "http://127.0.0.1:8080/",
+        ),
+        (
+            Some("http://nephelios.lan"),
+            None,
+            "http://nephelios.lan:3030/",
+        ),
+        (
+            Some("https://example.com/nephelios"),
+            None,
+            "https://example.com/nephelios/",
+        ),
+        (Some("https://example.com"), None, "https://example.com/"),
+        (
+            Some("http://example.com/nephelios"),
+            None,
+            "http://example.com/nephelios/",
+        ),
+        (
+            Some("https://example.com:8443"),
+            None,
+            "https://example.com:8443/",
+        ),
+        (
+            Some("https://example.com:443/nephelios/"),
+            Some(""),
+            "https://example.com/nephelios/",
+        ),
+        (
+            Some("https://example.com:443"),
+            Some("443"),
+            "https://example.com/",
+        ),
+        (Some("http://[::1]"), Some("8080"), "http://[::1]:8080/"),
+    ];
+    for (url, port, expected) in cases {
+        assert_eq!(
+            parse_server_url(url, port).unwrap().as_str(),
+            expected,
+            "{:?} {:?}",
+            url,
+            port
+        );
+    }
+}
+
+/// Tests that invalid server settings are rejected instead of falling back to defaults.
+#[test]
+fn test_parse_server_url_invalid() {
+    let cases = [
+        (Some("localhost:3030"), None),
+        (Some("ftp://example.com"), None),
+        (Some("https://example.com/?a=b"), None),
+        (Some("not a url"), None),
+        (None, Some("http")),
+        (None, Some("0")),
+        (None, Some("70000")),
+        (Some("http://127.0.0.1:8080"), Some("3030")),
+        (Some("https://example.com:443"), Some("3030")),
+        (Some("https://example.com/nephelios"), Some("3030")),
+    ];
+    for (url, port) in cases {
+        assert!(parse_server_url(url, port).is_err(), "{:?} {:?}", url, port);
+    }
+}
+
+/// Tests that endpoints keep the path prefix of the server URL.
+#[test]
+fn test_endpoint_url() {
+    let base = parse_server_url(Some("https://example.com/nephelios"), None).unwrap();
+    assert_eq!(
+        endpoint_url(&base, "/apps/my-app").as_str(),
+        "https://example.com/nephelios/apps/my-app"
+    );
+    assert_eq!(
+        endpoint_url(&base, "create").as_str(),
+        "https://example.com/nephelios/create"
+    );
+    assert_eq!(
+        endpoint_url(&base, "/").as_str(),
+        "https://example.com/nephelios/"
+    );
+}
+
+/// Tests the `unix://` form of `NEPHELIOS_URL`.
+#[test]
+fn test_parse_unix_socket() {
+    use crate::utils::server_url::parse_unix_socket;
+    use std::path::PathBuf;
+
+    assert_eq!(
+        parse_unix_socket(Some("unix:///run/nephelios.sock")).unwrap(),
+        Some(PathBuf::from("/run/nephelios.sock"))
+    );
+    assert_eq!(
+        parse_unix_socket(Some("https://example.com")).unwrap(),
+        None
+    );
+    assert_eq!(parse_unix_socket(None).unwrap(), None);
+    assert!(parse_unix_socket(Some("unix://nephelios.sock")).is_err());
+}
@@ -0,0 +1,58 @@
+use crate::utils::config::TlsOptions;
+use assert_cmd::Command;
+use std::path::PathBuf;
+
+/// Tests that TLS options given on the command line override the context's.
+#[test]
+fn test_tls_options_merge() {
+    let stored = TlsOptions {
+        ca_cert: Some(PathBuf::from("/etc/nephelios/ca.pem")),
+        insecure_skip_verify: Some(true),
+        ..Default::default()
+    };
+    let overrides = TlsOptions {
+        insecure_skip_verify: Some(false),
+        ..Default::default()
+    };
+
+    let merged = stored.merge(&overrides);
+    assert_eq!(merged.ca_cert, Some(PathBuf::from("/etc/nephelios/ca.pem")));
+    assert!(!merged.skips_verification());
+}
+
+/// Tests that TLS options are saved in a context, and that a client
+/// certificate is refused without its key.
+#[test]
+fn test_context_set_tls() {
+    let dir = tempfile::tempdir().unwrap();
+    let config = dir.path().join("config.toml");
+    let ca_cert = dir.path().join("ca.pem");
+    std::fs::write(&ca_cert, "").unwrap();
+
+    Command::cargo_bin("nephelios-cli")
+        .unwrap()
+        .env("NEPHELIOS_CONFIG", &config)
+        .args([
+            "context",
+            "set",
+            "staging",
+            "--insecure-skip-verify",
+            "--ca-cert",
+        ])
+        .arg(&ca_cert)
+        .assert()
+        .success();
+
+    let saved = std::fs::read_to_string(&config).unwrap();
+    assert!(saved.contains("insecure_skip_verify = true"), "{}", saved);
+    assert!(saved.contains("ca.pem"), "{}", saved);
+
+    Command::cargo_bin("nephelios-cli")
+        .unwrap()
+        .env("NEPHELIOS_CONFIG", &config)
+        .args(["context", "set", "staging", "--client-cert"])
+        .arg(&ca_cert)
+        .assert()
+        .failure()
+        .stderr(predicates::str::contains("--client-key"));
+}
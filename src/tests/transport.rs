@@ -0,0 +1,83 @@
+use crate::api::client::NepheliosClient;
+use crate::utils::config::ContextConfig;
+use serde_json::json;
+use url::Url;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+/// Tests that requests go through the proxy configured in the context.
+#[tokio::test]
+async fn test_context_proxy() {
+    let proxy = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/apps/my-app/status"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(json!({ "replicas": 1, "running": 1 })),
+        )
+        .mount(&proxy)
+        .await;
+
+    let settings = ContextConfig {
+        proxy: Some(proxy.uri()),
+        ..Default::default()
+    };
+    let base_url = Url::parse("http://nephelios.invalid:3030/").unwrap();
+    let client = NepheliosClient::for_server(base_url, settings, None).unwrap();
+
+    assert!(client.app_status("my-app").await.unwrap().is_converged());
+    let requests = proxy.received_requests().await.unwrap();
+    assert_eq!(requests[0].url.host_str(), Some("nephelios.invalid"));
+}
+
+/// Tests that requests reach a server listening on a Unix domain socket.
+#[cfg(unix)]
+#[tokio::test]
+async fn test_unix_socket() {
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::UnixListener;
+
+    let dir = tempfile::tempdir().unwrap();
+    let socket = dir.path().join("nephelios.sock");
+    let listener = UnixListener::bind(&socket).unwrap();
+
+    let server = tokio::spawn(async move {
+        let (mut stream, _) = listener.accept().await.unwrap();
+        let mut request = Vec::new();
+        let mut buffer = [0; 1024];
+        while !request.ends_with(b"\r\n\r\n") {
+            let read = stream.read(&mut buffer).await.unwrap();
+            request.extend_from_slice(&buffer[..read]);
+        }
+
+        let body = r#"{"replicas": 2, "running": 1}"#;
+        let response = format!(
+            "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\n\r\n{}",
+            body.len(),
+            body
+        );
+        stream.write_all(response.as_bytes()).await.unwrap();
+        String::from_utf8(request).unwrap()
+    });
+
+    let client =
+        NepheliosClient::for_socket(socket.clone(), ContextConfig::default(), Some("secret"))
+            .unwrap();
+    let status = client.app_status("my-app").await.unwrap();
+    assert_eq!((status.replicas, status.running), (2, 1));
+    assert_eq!(
+        client.base_url(),
+        format!("unix://{}", socket.display()).as_str()
+    );
+
+    let request = server.await.unwrap().to_lowercase();
+    assert!(
+        request.starts_with("get /apps/my-app/status http/1.1"),
+        "{}",
+        request
+    );
+    assert!(
+        request.contains("authorization: bearer secret"),
+        "{}",
+        request
+    );
+}
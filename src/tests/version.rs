@@ -0,0 +1,58 @@
+use crate::api::version::{ServerVersion, API_VERSION};
+use crate::tests::test_client;
+use crate::utils::version_cache::{VersionCache, CACHE_TTL};
+use std::time::{Duration, SystemTime};
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+fn server(api_version: u32) -> ServerVersion {
+    ServerVersion {
+        version: "0.4.2".to_string(),
+        api_version,
+    }
+}
+
+/// Tests that a mismatch tells which side to update.
+#[test]
+fn test_version_mismatch() {
+    assert_eq!(server(API_VERSION).mismatch(), None);
+    assert!(server(API_VERSION + 1)
+        .mismatch()
+        .unwrap()
+        .contains("Update nephelios-cli"));
+    assert!(server(API_VERSION - 1)
+        .mismatch()
+        .unwrap()
+        .contains("nephelios-cli up"));
+}
+
+/// Tests that the version of a server is only trusted for a while.
+#[test]
+fn test_version_cache() {
+    let now = SystemTime::now();
+    let mut cache = VersionCache::default();
+    assert_eq!(cache.get("http://localhost:3030/", now), None);
+
+    cache.insert("http://localhost:3030/", Some(&server(API_VERSION)), now);
+    cache.insert("unix:///run/nephelios.sock", None, now);
+    assert_eq!(
+        cache.get("http://localhost:3030/", now + Duration::from_secs(60)),
+        Some(Some(server(API_VERSION)))
+    );
+    assert_eq!(cache.get("unix:///run/nephelios.sock", now), Some(None));
+    assert_eq!(cache.get("http://localhost:3030/", now + CACHE_TTL), None);
+}
+
+/// Tests that the compatibility check does not retry, leaving that to the request it precedes.
+#[tokio::test]
+async fn test_probe_server_version_is_not_retried() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/version"))
+        .respond_with(ResponseTemplate::new(503))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    assert!(test_client(&server).probe_server_version().await.is_err());
+}
@@ -0,0 +1,200 @@
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+
+/// Complete description of an application, as exchanged with the server.
+///
+/// The same structure describes both the desired state read from a manifest
+/// and the live state reported by the server, so the two can be compared.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct AppSpec {
+    pub app_name: String,
+
+    #[serde(default)]
+    pub app_type: String,
+
+    #[serde(default)]
+    pub github_url: String,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub git_ref: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+
+    #[serde(default)]
+    pub env: BTreeMap<String, String>,
+
+    #[serde(default = "default_replicas")]
+    pub replicas: u32,
+
+    #[serde(default)]
+    pub domains: Vec<String>,
+
+    #[serde(default)]
+    pub resources: Resources,
+}
+
+/// Resource limits applied to each replica of an application.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Resources {
+    /// Number of CPUs, possibly fractional (e.g., 0.5)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cpus: Option<f64>,
+
+    /// Memory limit with a unit suffix (e.g., 512M)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub memory: Option<String>,
+}
+
+pub fn default_replicas() -> u32 {
+    1
+}
+
+/// Placeholder printed instead of environment variable values.
+pub const MASK: &str = "********";
+
+/// A single field that differs between two application states.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FieldChange {
+    pub field: String,
+    pub old: Option<String>,
+    pub new: Option<String>,
+}
+
+impl FieldChange {
+    /// Whether the values of this field may hold secrets, and must not be printed.
+    pub fn is_masked(&self) -> bool {
+        self.field.starts_with("env.")
+    }
+}
+
+impl fmt::Display for FieldChange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_masked() {
+            return match (&self.old, &self.new) {
+                (None, Some(_)) => write!(f, "+ {}: {}", self.field, MASK),
+                (Some(_), None) => write!(f, "- {}: {}", self.field, MASK),
+                (Some(_), Some(_)) => write!(f, "~ {}: changed", self.field),
+                (None, None) => write!(f, "  {}", self.field),
+            };
+        }
+
+        match (&self.old, &self.new) {
+            (None, Some(new)) => write!(f, "+ {}: {}", self.field, new),
+            (Some(old), None) => write!(f, "- {}: {}", self.field, old),
+            (Some(old), Some(new)) => write!(f, "~ {}: {} -> {}", self.field, old, new),
+            (None, None) => write!(f, "  {}", self.field),
+        }
+    }
+}
+
+impl AppSpec {
+    /// Lists the fields that must change to turn this state into `desired`.
+    ///
+    /// Environment variables and domains are compared entry by entry, so that
+    /// only the affected keys are reported.
+    pub fn changes(&self, desired: &AppSpec) -> Vec<FieldChange> {
+        let mut changes = vec![];
+
+        push_change(
+            &mut changes,
+            "type",
+            Some(&self.app_type),
+            Some(&desired.app_type),
+        );
+        push_change(
+            &mut changes,
+            "github_url",
+            Some(&self.github_url),
+            Some(&desired.github_url),
+        );
+        push_change(
+            &mut changes,
+            "ref",
+            self.git_ref.as_ref(),
+            desired.git_ref.as_ref(),
+        );
+        push_change(
+            &mut changes,
+            "path",
+            self.path.as_ref(),
+            desired.path.as_ref(),
+        );
+        push_change(
+            &mut changes,
+            "replicas",
+            Some(&self.replicas),
+            Some(&desired.replicas),
+        );
+
+        let keys: BTreeSet<&String> = self.env.keys().chain(desired.env.keys()).collect();
+        for key in keys {
+            push_change(
+                &mut changes,
+                &format!("env.{}", key),
+                self.env.get(key),
+                desired.env.get(key),
+            );
+        }
+
+        for domain in &self.domains {
+            if !desired.domains.contains(domain) {
+                push_change(&mut changes, "domains", Some(domain), None);
+            }
+        }
+        for domain in &desired.domains {
+            if !self.domains.contains(domain) {
+                push_change(&mut changes, "domains", None, Some(domain));
+            }
+        }
+
+        push_change(
+            &mut changes,
+            "resources.cpus",
+            self.resources.cpus.as_ref(),
+            desired.resources.cpus.as_ref(),
+        );
+        push_change(
+            &mut changes,
+            "resources.memory",
+            self.resources.memory.as_ref(),
+            desired.resources.memory.as_ref(),
+        );
+
+        changes
+    }
+
+    /// Lists every field set by this state, as additions to an empty application.
+    pub fn creation_changes(&self) -> Vec<FieldChange> {
+        let empty = AppSpec {
+            app_name: self.app_name.clone(),
+            replicas: 0,
+            ..Default::default()
+        };
+
+        empty
+            .changes(self)
+            .into_iter()
+            .map(|change| FieldChange {
+                old: None,
+                ..change
+            })
+            .collect()
+    }
+}
+
+fn push_change<T: ToString + PartialEq>(
+    changes: &mut Vec<FieldChange>,
+    field: &str,
+    old: Option<&T>,
+    new: Option<&T>,
+) {
+    if old != new {
+        changes.push(FieldChange {
+            field: field.to_string(),
+            old: old.map(ToString::to_string),
+            new: new.map(ToString::to_string),
+        });
+    }
+}
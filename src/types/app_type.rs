@@ -0,0 +1,84 @@
+use anyhow::{bail, Result};
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::path::Path;
+
+/// Type of application deployed on the Nephelios platform.
+///
+/// The type selects the build image used by the server.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AppType {
+    /// Node.js application, detected from `package.json`
+    Nodejs,
+    /// Python application, detected from `pyproject.toml` or `requirements.txt`
+    Python,
+    /// Rust application, detected from `Cargo.toml`
+    Rust,
+    /// Application built from its own `Dockerfile`
+    Docker,
+}
+
+impl AppType {
+    /// Returns the name of the type as expected by the server.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AppType::Nodejs => "nodejs",
+            AppType::Python => "python",
+            AppType::Rust => "rust",
+            AppType::Docker => "docker",
+        }
+    }
+
+    /// Detects the application type from the files present in a project directory.
+    ///
+    /// Language manifests take precedence over a `Dockerfile`, which is only used
+    /// when no language could be recognised.
+    ///
+    /// # Arguments
+    ///
+    /// * `dir` - The root directory of the application
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(AppType)` if exactly one application type was recognised
+    /// * `Err(anyhow::Error)` if the type is unknown or ambiguous
+    pub fn detect(dir: &Path) -> Result<Self> {
+        let markers: [(AppType, &[&str]); 3] = [
+            (AppType::Nodejs, &["package.json"]),
+            (AppType::Python, &["pyproject.toml", "requirements.txt"]),
+            (AppType::Rust, &["Cargo.toml"]),
+        ];
+
+        let detected: Vec<AppType> = markers
+            .iter()
+            .filter(|(_, files)| files.iter().any(|file| dir.join(file).is_file()))
+            .map(|(app_type, _)| *app_type)
+            .collect();
+
+        match detected.as_slice() {
+            [app_type] => Ok(*app_type),
+            [] if dir.join("Dockerfile").is_file() => Ok(AppType::Docker),
+            [] => bail!(
+                "Could not detect the application type in {}. Please specify --type_",
+                dir.display()
+            ),
+            _ => bail!(
+                "Found several application types in {} ({}). Please specify --type_",
+                dir.display(),
+                detected
+                    .iter()
+                    .map(AppType::as_str)
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+        }
+    }
+}
+
+impl fmt::Display for AppType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
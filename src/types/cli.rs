@@ -1,4 +1,11 @@
-use clap::{Parser, Subcommand};
+use crate::api::retry::{RetryPolicy, DEFAULT_RETRIES, DEFAULT_TIMEOUT};
+use crate::types::app_type::AppType;
+use crate::types::manifest::MANIFEST_FILE;
+use crate::types::role::Role;
+use crate::utils::config::TlsOptions;
+use clap::{Args, Parser, Subcommand};
+use std::path::PathBuf;
+use std::time::Duration;
 
 /// Represents the main CLI configuration and command structure.
 ///
@@ -10,44 +17,183 @@ use clap::{Parser, Subcommand};
     author = "Your Name <your.email@example.com>",
     version = env!("CARGO_PKG_VERSION"),
     about = "Nephelios CLI tool for managing application deployments",
-    long_about = "A command-line interface for managing your Nephelios deployments. Supports creating new deployments, \
+    long_about = "Nephelios CLI tool for managing application deployments.\n\n\
+                  A command-line interface for managing your Nephelios deployments. Supports creating new deployments, \
                   managing existing ones, and deploying applications from GitHub repositories."
 )]
 pub struct Cli {
+    /// Named context to use instead of the current one
+    #[arg(
+        long,
+        global = true,
+        env = "NEPHELIOS_CONTEXT",
+        help = "Configuration context to use for this command"
+    )]
+    pub context: Option<String>,
+
+    #[arg(
+        long,
+        global = true,
+        value_name = "PEM",
+        help = "CA certificates to trust for the server, in addition to the system ones"
+    )]
+    pub ca_cert: Option<PathBuf>,
+
+    #[arg(
+        long,
+        global = true,
+        value_name = "PEM",
+        requires = "client_key",
+        help = "Client certificate presented to the server for mutual TLS"
+    )]
+    pub client_cert: Option<PathBuf>,
+
+    #[arg(
+        long,
+        global = true,
+        value_name = "PEM",
+        requires = "client_cert",
+        help = "PKCS#8 private key of the client certificate"
+    )]
+    pub client_key: Option<PathBuf>,
+
+    #[arg(
+        long,
+        global = true,
+        num_args = 0..=1,
+        require_equals = true,
+        default_missing_value = "true",
+        value_name = "BOOL",
+        help = "Do not verify the server certificate (unsafe, for testing only)"
+    )]
+    pub insecure_skip_verify: Option<bool>,
+
+    #[arg(
+        short,
+        long,
+        global = true,
+        action = clap::ArgAction::Count,
+        help = "Log API requests: -v for status and timings, -vv for headers, -vvv for redacted bodies"
+    )]
+    pub verbose: u8,
+
+    #[arg(
+        long,
+        global = true,
+        value_name = "PATH",
+        help = "Append the logs to a file instead of stderr (at least at -vv)"
+    )]
+    pub log_file: Option<PathBuf>,
+
+    /// Time a single API request may take, builds excepted
+    #[arg(
+        long,
+        global = true,
+        env = "NEPHELIOS_REQUEST_TIMEOUT",
+        value_name = "SECONDS",
+        default_value_t = DEFAULT_TIMEOUT.as_secs(),
+        value_parser = clap::value_parser!(u64).range(1..),
+        help = "Timeout of API requests, in seconds"
+    )]
+    pub request_timeout: u64,
+
+    /// Number of times a request failing transiently is retried
+    #[arg(
+        long,
+        global = true,
+        env = "NEPHELIOS_RETRIES",
+        default_value_t = DEFAULT_RETRIES,
+        help = "Retries of API requests failing transiently"
+    )]
+    pub retries: u32,
+
     #[command(subcommand)]
     pub command: Commands,
 }
 
+impl Cli {
+    /// Returns the retry policy given on the command line.
+    pub fn retry_policy(&self) -> RetryPolicy {
+        RetryPolicy {
+            retries: self.retries,
+            timeout: Duration::from_secs(self.request_timeout),
+        }
+    }
+
+    /// Returns the TLS options given on the command line.
+    pub fn tls_options(&self) -> TlsOptions {
+        TlsOptions {
+            ca_cert: self.ca_cert.clone(),
+            client_cert: self.client_cert.clone(),
+            client_key: self.client_key.clone(),
+            insecure_skip_verify: self.insecure_skip_verify,
+        }
+    }
+}
+
 #[derive(Subcommand)]
 pub enum Commands {
     /// Create a new application deployment
     ///
     /// This command creates a new deployment of your application on the Nephelios platform.
-    /// It requires the application name, type, and GitHub repository URL.
+    /// It requires the application name and GitHub repository URL. The application type
+    /// is detected from the local project when it is not given.
     Create {
         /// Name of the application (e.g., my-awesome-app)
         #[arg(long, help = "Name of the application to deploy")]
         name: String,
 
-        /// Type of the application (supported: nodejs, python, rust)
+        /// Type of the application (supported: nodejs, python, rust, docker)
         #[arg(
             long,
+            value_enum,
             help = "Type of application to deploy",
             long_help = "Specify the type of application you're deploying. Currently supported types:\n\
                         - nodejs: For Node.js applications\n\
                         - python: For Python applications\n\
-                        - rust: For Rust applications"
+                        - rust: For Rust applications\n\
+                        - docker: For applications built from their own Dockerfile\n\
+                        When omitted, the type is detected from the files in --path or the current directory."
+        )]
+        type_: Option<AppType>,
+
+        /// Local checkout of the application, used to detect its type
+        #[arg(
+            long,
+            help = "Local directory of the application, used to detect its type [default: current directory]"
         )]
-        type_: String,
+        path: Option<PathBuf>,
 
         /// GitHub repository URL containing your application code
         #[arg(
             long,
             help = "URL of the GitHub repository containing your application",
-            long_help = "The full HTTPS URL of your GitHub repository. The repository must be public \
-                        or you must have configured appropriate access credentials."
+            long_help = "The URL of your GitHub repository, either over HTTPS \
+                        (https://github.com/owner/repo) or SSH (git@github.com:owner/repo.git). \
+                        Private repositories require --git-token, --git-ssh-key or credentials \
+                        stored in the current context."
         )]
         github_url: String,
+
+        /// Access token used to clone a private repository over HTTPS
+        #[arg(
+            long,
+            env = "NEPHELIOS_GIT_TOKEN",
+            hide_env_values = true,
+            help = "Access token for a private HTTPS repository"
+        )]
+        git_token: Option<String>,
+
+        /// Private key used to clone a private repository over SSH
+        #[arg(long, help = "Path to a private SSH key for a private SSH repository")]
+        git_ssh_key: Option<PathBuf>,
+
+        /// Return as soon as the server accepted the deployment
+        #[arg(
+            long,
+            help = "Queue the deployment and print its job ID instead of waiting for the build"
+        )]
+        detach: bool,
     },
 
     /// Remove an existing application deployment.
@@ -72,7 +218,689 @@ pub enum Commands {
         name: String,
     },
 
+    /// Restart an application without downtime.
+    ///
+    /// Replicas are restarted one at a time, and the command returns once all of
+    /// them are running again.
+    Restart {
+        /// Name of the application (e.g., my-awesome-app)
+        #[arg(long, help = "Name of the application to restart")]
+        name: String,
+
+        #[arg(
+            long,
+            default_value_t = 300,
+            value_name = "SECONDS",
+            help = "How long to wait for the replicas to run again"
+        )]
+        timeout: u64,
+    },
+
+    /// Rebuild an application from the latest commit of its repository.
+    ///
+    /// The build output is streamed to the terminal, and the running service is
+    /// updated without downtime once the new image is ready.
+    Redeploy {
+        /// Name of the application (e.g., my-awesome-app)
+        #[arg(long, help = "Name of the application to redeploy")]
+        name: String,
+
+        #[arg(
+            long = "ref",
+            value_name = "REF",
+            help = "Branch, tag or commit to build [default: the stored one]"
+        )]
+        git_ref: Option<String>,
+    },
+
+    /// List the release history of an application.
+    ///
+    /// Each release records the commit it was built from, its creation time, the
+    /// digest of its image in the platform registry and its status.
+    Releases {
+        /// Name of the application (e.g., my-awesome-app)
+        #[arg(long, help = "Name of the application")]
+        name: String,
+    },
+
+    /// Redeploy a previous release of an application.
+    ///
+    /// The release image is taken from the platform registry, so nothing is rebuilt.
+    /// Without --to, the last good release before the active one is used.
+    Rollback {
+        /// Name of the application (e.g., my-awesome-app)
+        #[arg(long, help = "Name of the application to roll back")]
+        name: String,
+
+        #[arg(long, value_name = "RELEASE", help = "Release number to redeploy")]
+        to: Option<u32>,
+    },
+
+    /// Change the number of replicas of an application.
+    ///
+    /// This command updates the application's swarm service and waits until all of
+    /// its tasks are running. Servers that support autoscaling also accept bounds.
+    Scale {
+        /// Name of the application (e.g., my-awesome-app)
+        #[arg(long, help = "Name of the application to scale")]
+        name: String,
+
+        #[arg(
+            long,
+            required_unless_present_any = ["min", "max"],
+            help = "Number of replicas to run"
+        )]
+        replicas: Option<u32>,
+
+        #[arg(long, help = "Minimum number of replicas when autoscaling")]
+        min: Option<u32>,
+
+        #[arg(long, help = "Maximum number of replicas when autoscaling")]
+        max: Option<u32>,
+
+        #[arg(long, help = "Return without waiting for the tasks to run")]
+        no_wait: bool,
+
+        #[arg(
+            long,
+            default_value_t = 300,
+            value_name = "SECONDS",
+            help = "How long to wait for the tasks to run"
+        )]
+        wait_timeout: u64,
+    },
+
     Up {},
 
     Down {},
+
+    /// Create or update an application from its manifest.
+    ///
+    /// This command reads a nephelios.toml manifest and creates the application,
+    /// or updates the fields that differ from the deployed one.
+    Apply {
+        /// Path of the manifest file
+        #[arg(
+            short,
+            long,
+            default_value = MANIFEST_FILE,
+            help = "Path of the application manifest"
+        )]
+        file: PathBuf,
+    },
+
+    /// Preview the changes `apply` would make.
+    ///
+    /// This command compares the manifest with the deployed application field by
+    /// field without changing anything. It exits with code 2 when they differ.
+    #[command(visible_alias = "plan")]
+    Diff {
+        /// Path of the manifest file
+        #[arg(
+            short,
+            long,
+            default_value = MANIFEST_FILE,
+            help = "Path of the application manifest"
+        )]
+        file: PathBuf,
+    },
+
+    /// Scaffold a new application project.
+    ///
+    /// This command asks for the application name, type and repository, writes a
+    /// nephelios.toml manifest and optionally a starter Dockerfile, and offers to
+    /// deploy the application right away.
+    Init {
+        /// Path of the manifest file to write
+        #[arg(
+            short,
+            long,
+            default_value = MANIFEST_FILE,
+            help = "Path of the application manifest to write"
+        )]
+        file: PathBuf,
+
+        #[arg(long, help = "Overwrite an existing manifest")]
+        force: bool,
+    },
+
+    /// Manage the local Nephelios platform.
+    Platform {
+        #[command(subcommand)]
+        command: PlatformCommands,
+    },
+
+    /// Manage the environment variables of an application.
+    ///
+    /// Values are masked in the output unless --reveal is given. Applications pick
+    /// up changes on their next restart, or immediately with --restart.
+    Env {
+        #[command(subcommand)]
+        command: EnvCommands,
+    },
+
+    /// Manage the secrets of an application.
+    ///
+    /// Secrets are stored by the server as Docker swarm secrets and mounted in the
+    /// application under /run/secrets. Their values are never printed.
+    Secret {
+        #[command(subcommand)]
+        command: SecretCommands,
+    },
+
+    /// Manage the host names routed to an application.
+    ///
+    /// A host given as a single label (e.g., api) is completed with the domain
+    /// suffix of the current context.
+    Domains {
+        #[command(subcommand)]
+        command: DomainsCommands,
+    },
+
+    /// Follow and control deployments running on the server.
+    ///
+    /// Deployments started with `create --detach`, or detached with Ctrl+C, keep
+    /// running on the server as jobs.
+    Jobs {
+        #[command(subcommand)]
+        command: JobsCommands,
+    },
+
+    /// Log in to the Nephelios server of the current context.
+    ///
+    /// The API token received in exchange for your credentials is stored in the
+    /// system keyring, and sent with every request made with this context.
+    ///
+    /// With --device, log in through your identity provider in a browser instead.
+    Login {
+        #[arg(long, help = "User to log in as [default: prompted]")]
+        username: Option<String>,
+
+        #[arg(
+            long,
+            help = "Read the password from standard input instead of a prompt"
+        )]
+        password_stdin: bool,
+
+        #[arg(
+            long,
+            conflicts_with_all = ["username", "password_stdin"],
+            help = "Log in through single sign-on with a code entered in a browser"
+        )]
+        device: bool,
+
+        #[arg(
+            long,
+            requires = "device",
+            help = "Issuer URL of the identity provider [default: the context's]"
+        )]
+        issuer: Option<String>,
+
+        #[arg(
+            long,
+            requires = "device",
+            help = "OAuth client ID of the CLI [default: the context's, then nephelios-cli]"
+        )]
+        client_id: Option<String>,
+    },
+
+    /// Log out of the current context, revoking its API token.
+    Logout {},
+
+    /// Show the user the current context is logged in as.
+    Whoami {},
+
+    /// Show the versions of the CLI, the server and the platform image.
+    ///
+    /// Also tells whether the CLI and the server speak the same API version. Every
+    /// other command checks this too, at most once an hour per server, and warns
+    /// when they do not.
+    Version {},
+
+    /// Manage long-lived API tokens, for CI pipelines and scripts.
+    ///
+    /// Set NEPHELIOS_TOKEN to a token secret to authenticate with it instead of
+    /// the session of the current context.
+    Token {
+        #[command(subcommand)]
+        command: TokenCommands,
+    },
+
+    /// Manage the users of the platform and their roles.
+    ///
+    /// Platform roles apply to every application; grants give a user a role on a
+    /// single application. Roles are viewer, deployer and admin, each including
+    /// the permissions of the previous one.
+    Users {
+        #[command(subcommand)]
+        command: UsersCommands,
+    },
+
+    /// Manage configuration contexts.
+    ///
+    /// A context groups the settings used to talk to one Nephelios installation,
+    /// such as the Git credentials sent when creating applications.
+    Context {
+        #[command(subcommand)]
+        command: ContextCommands,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum PlatformCommands {
+    /// Restart the Nephelios container and wait until its API answers
+    Restart {
+        #[arg(
+            long,
+            default_value_t = 120,
+            value_name = "SECONDS",
+            help = "How long to wait for Nephelios to be healthy"
+        )]
+        timeout: u64,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum EnvCommands {
+    /// List the environment variables of an application
+    List {
+        /// Name of the application (e.g., my-awesome-app)
+        #[arg(long, help = "Name of the application")]
+        name: String,
+
+        #[arg(long, help = "Print the values instead of masking them")]
+        reveal: bool,
+    },
+
+    /// Set one or more environment variables
+    Set {
+        /// Name of the application (e.g., my-awesome-app)
+        #[arg(long, help = "Name of the application")]
+        name: String,
+
+        /// Variables to set (e.g., NODE_ENV=production)
+        #[arg(required = true, value_name = "KEY=VALUE", value_parser = parse_key_value)]
+        vars: Vec<(String, String)>,
+
+        #[arg(long, help = "Perform a rolling restart to apply the changes")]
+        restart: bool,
+    },
+
+    /// Remove one or more environment variables
+    Unset {
+        /// Name of the application (e.g., my-awesome-app)
+        #[arg(long, help = "Name of the application")]
+        name: String,
+
+        /// Names of the variables to remove
+        #[arg(required = true, value_name = "KEY")]
+        keys: Vec<String>,
+
+        #[arg(long, help = "Perform a rolling restart to apply the changes")]
+        restart: bool,
+    },
+
+    /// Set the environment variables defined in a .env file
+    Import {
+        /// Name of the application (e.g., my-awesome-app)
+        #[arg(long, help = "Name of the application")]
+        name: String,
+
+        #[arg(long, default_value = ".env", help = "Path of the .env file to import")]
+        file: PathBuf,
+
+        #[arg(long, help = "Perform a rolling restart to apply the changes")]
+        restart: bool,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum TokenCommands {
+    /// Create a token and print its secret, which is only shown once
+    Create {
+        #[arg(
+            long = "scope",
+            required = true,
+            value_delimiter = ',',
+            help = "What the token is allowed to do (e.g., deploy); repeat for several scopes"
+        )]
+        scopes: Vec<String>,
+
+        #[arg(
+            long,
+            value_name = "DURATION",
+            value_parser = parse_duration,
+            help = "How long the token is valid (e.g., 12h, 90d, 1w) [default: set by the server]"
+        )]
+        expires: Option<Duration>,
+
+        #[arg(long, help = "What the token is used for (e.g., github-actions)")]
+        name: Option<String>,
+    },
+
+    /// List your tokens, without their secrets
+    List {},
+
+    /// Revoke a token, so that it is rejected from now on
+    Revoke {
+        /// ID of the token, as shown by `token list`
+        id: String,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum UsersCommands {
+    /// List users, or the permissions granted on an application
+    List {
+        /// Name of the application (e.g., my-awesome-app)
+        #[arg(
+            long,
+            help = "List the permissions granted on this application instead"
+        )]
+        name: Option<String>,
+    },
+
+    /// Invite a new user by email
+    Invite {
+        /// Email address of the new user
+        email: String,
+
+        #[arg(long, value_enum, default_value_t = Role::Viewer, help = "Platform role of the new user")]
+        role: Role,
+    },
+
+    /// Remove a user and revoke their tokens
+    Remove {
+        /// Name of the user
+        username: String,
+    },
+
+    /// Change the platform role of a user
+    SetRole {
+        /// Name of the user
+        username: String,
+
+        /// New platform role
+        #[arg(value_enum)]
+        role: Role,
+    },
+
+    /// Grant a user a role on a single application
+    Grant {
+        /// Name of the user
+        username: String,
+
+        /// Name of the application (e.g., my-awesome-app)
+        #[arg(long, help = "Name of the application")]
+        name: String,
+
+        #[arg(long, value_enum, help = "Role on the application")]
+        role: Role,
+    },
+
+    /// Revoke the role of a user on a single application
+    Revoke {
+        /// Name of the user
+        username: String,
+
+        /// Name of the application (e.g., my-awesome-app)
+        #[arg(long, help = "Name of the application")]
+        name: String,
+    },
+}
+
+/// Parses a duration made of a number and a unit: `s`, `m`, `h`, `d` or `w` (e.g., `90d`).
+pub fn parse_duration(arg: &str) -> Result<Duration, String> {
+    let error = || format!("expected a duration such as 12h, 90d or 1w, got '{}'", arg);
+
+    let split = arg.find(|c: char| !c.is_ascii_digit()).ok_or_else(error)?;
+    let (amount, unit) = arg.split_at(split);
+    let amount: u64 = amount.parse().map_err(|_| error())?;
+    let seconds = match unit {
+        "s" => 1,
+        "m" => 60,
+        "h" => 60 * 60,
+        "d" => 24 * 60 * 60,
+        "w" => 7 * 24 * 60 * 60,
+        _ => return Err(error()),
+    };
+
+    if amount == 0 {
+        return Err(error());
+    }
+    amount
+        .checked_mul(seconds)
+        .map(Duration::from_secs)
+        .ok_or_else(|| format!("duration '{}' is too long", arg))
+}
+
+/// Parses a `KEY=VALUE` argument.
+fn parse_key_value(arg: &str) -> Result<(String, String), String> {
+    match arg.split_once('=') {
+        Some((key, value)) if !key.is_empty() => Ok((key.to_string(), value.to_string())),
+        _ => Err(format!("expected KEY=VALUE, got '{}'", arg)),
+    }
+}
+
+#[derive(Subcommand)]
+pub enum SecretCommands {
+    /// Create a secret from a file, standard input or a prompt
+    Create {
+        /// Name of the application (e.g., my-awesome-app)
+        #[arg(long, help = "Name of the application")]
+        name: String,
+
+        /// Name of the secret (e.g., DB_PASSWORD)
+        secret: String,
+
+        #[arg(long, help = "Read the value from a file instead of a prompt")]
+        from_file: Option<PathBuf>,
+
+        #[arg(long, help = "Mount the secret in the application right away")]
+        attach: bool,
+    },
+
+    /// List the secrets of an application
+    List {
+        /// Name of the application (e.g., my-awesome-app)
+        #[arg(long, help = "Name of the application")]
+        name: String,
+    },
+
+    /// Remove a secret, detaching it from the application first
+    Rm {
+        /// Name of the application (e.g., my-awesome-app)
+        #[arg(long, help = "Name of the application")]
+        name: String,
+
+        /// Name of the secret (e.g., DB_PASSWORD)
+        secret: String,
+    },
+
+    /// Mount a secret in the application under /run/secrets
+    Attach {
+        /// Name of the application (e.g., my-awesome-app)
+        #[arg(long, help = "Name of the application")]
+        name: String,
+
+        /// Name of the secret (e.g., DB_PASSWORD)
+        secret: String,
+
+        #[arg(long, help = "File name under /run/secrets [default: the secret name]")]
+        target: Option<String>,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum DomainsCommands {
+    /// Route a host name to an application
+    Add {
+        /// Name of the application (e.g., my-awesome-app)
+        #[arg(long, help = "Name of the application")]
+        name: String,
+
+        /// Host name (e.g., my-app.example.com)
+        host: String,
+
+        #[arg(
+            long,
+            help = "Serve the host over HTTPS with a certificate requested and renewed through ACME"
+        )]
+        tls: bool,
+
+        #[arg(
+            long,
+            requires = "tls",
+            help = "ACME directory URL, such as a local Pebble [default: the context's, then Let's Encrypt]"
+        )]
+        acme_directory: Option<String>,
+
+        #[arg(
+            long,
+            requires = "tls",
+            help = "Contact address for certificate expiry notices"
+        )]
+        acme_email: Option<String>,
+    },
+
+    /// List the host names of an application, their URLs and certificates
+    List {
+        /// Name of the application (e.g., my-awesome-app)
+        #[arg(long, help = "Name of the application")]
+        name: String,
+    },
+
+    /// Stop routing a host name to an application
+    Rm {
+        /// Name of the application (e.g., my-awesome-app)
+        #[arg(long, help = "Name of the application")]
+        name: String,
+
+        /// Host name (e.g., my-app.example.com)
+        host: String,
+    },
+
+    /// Manage the TLS certificates of domains
+    Cert {
+        #[command(subcommand)]
+        command: CertCommands,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum CertCommands {
+    /// Serve your own certificate for a domain instead of an ACME one
+    Upload {
+        /// Name of the application (e.g., my-awesome-app)
+        #[arg(long, help = "Name of the application")]
+        name: String,
+
+        /// Host name (e.g., my-app.example.com)
+        host: String,
+
+        #[arg(long, help = "PEM file holding the certificate chain")]
+        cert: PathBuf,
+
+        #[arg(long, help = "PEM file holding the private key")]
+        key: PathBuf,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum JobsCommands {
+    /// List the jobs known to the server
+    List {},
+
+    /// Wait for a job to finish, showing its progress
+    Wait {
+        /// ID of the job
+        job_id: String,
+
+        #[arg(long, help = "Give up after this many seconds [default: wait forever]")]
+        timeout: Option<u64>,
+    },
+
+    /// Cancel a queued or running job
+    Cancel {
+        /// ID of the job
+        job_id: String,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum ContextCommands {
+    /// List all configured contexts
+    List {},
+
+    /// Select the context used by default
+    Use {
+        /// Name of the context (e.g., staging)
+        name: String,
+    },
+
+    /// Create or update a context
+    ///
+    /// The global --ca-cert, --client-cert, --client-key and --insecure-skip-verify
+    /// options are saved in the context too. Pass `--insecure-skip-verify=false`
+    /// to turn verification back on.
+    Set {
+        /// Name of the context (e.g., staging)
+        name: String,
+
+        #[command(flatten)]
+        settings: Box<ContextSettings>,
+    },
+
+    /// Delete a context
+    Remove {
+        /// Name of the context (e.g., staging)
+        name: String,
+    },
+}
+
+/// Settings of `context set`, boxed in [`ContextCommands::Set`] to keep the enum small.
+#[derive(Args)]
+pub struct ContextSettings {
+    #[arg(long, help = "Access token for private HTTPS repositories")]
+    pub git_token: Option<String>,
+
+    #[arg(long, help = "Path to a private SSH key for private SSH repositories")]
+    pub git_ssh_key: Option<PathBuf>,
+
+    #[arg(
+        long,
+        help = "Domain under which applications get their default host name (e.g., apps.example.com)"
+    )]
+    pub domain_suffix: Option<String>,
+
+    #[arg(
+        long,
+        help = "ACME directory used for `domains add --tls` [default: Let's Encrypt]"
+    )]
+    pub acme_directory: Option<String>,
+
+    #[arg(long, help = "Contact address registered with the ACME directory")]
+    pub acme_email: Option<String>,
+
+    #[arg(
+        long,
+        help = "Issuer URL of the identity provider used by `login --device`"
+    )]
+    pub oidc_issuer: Option<String>,
+
+    #[arg(long, help = "OAuth client ID of the CLI at the identity provider")]
+    pub oidc_client_id: Option<String>,
+
+    #[arg(
+        long,
+        help = "Proxy used to reach the server (e.g., http://proxy.example.com:3128) [default: HTTPS_PROXY]"
+    )]
+    pub proxy: Option<String>,
+
+    #[arg(
+        long,
+        value_name = "HOSTS",
+        help = "Comma-separated hosts reached without the proxy of the context"
+    )]
+    pub no_proxy: Option<String>,
 }
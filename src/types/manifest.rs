@@ -0,0 +1,139 @@
+use crate::types::app_spec::{default_replicas, AppSpec, Resources};
+use crate::types::app_type::AppType;
+use anyhow::{bail, Context as _, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Default file name of an application manifest.
+pub const MANIFEST_FILE: &str = "nephelios.toml";
+
+/// Declarative description of an application, stored in `nephelios.toml`.
+///
+/// # Examples
+///
+/// ```toml
+/// name = "my-app"
+/// type = "nodejs"
+/// replicas = 2
+/// domains = ["my-app.example.com"]
+///
+/// [source]
+/// github_url = "https://github.com/user/repo"
+/// ref = "main"
+///
+/// [env]
+/// NODE_ENV = "production"
+///
+/// [resources]
+/// cpus = 0.5
+/// memory = "512M"
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Manifest {
+    pub name: String,
+
+    /// Detected from the project files next to the manifest when omitted.
+    #[serde(rename = "type", default, skip_serializing_if = "Option::is_none")]
+    pub app_type: Option<AppType>,
+
+    pub source: Source,
+
+    #[serde(default = "default_replicas")]
+    pub replicas: u32,
+
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub domains: Vec<String>,
+
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub env: BTreeMap<String, String>,
+
+    #[serde(default, skip_serializing_if = "is_unlimited")]
+    pub resources: Resources,
+}
+
+/// Location of the application source code.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Source {
+    pub github_url: String,
+
+    /// Branch, tag or commit to build, defaulting to the repository's default branch.
+    #[serde(rename = "ref", default, skip_serializing_if = "Option::is_none")]
+    pub git_ref: Option<String>,
+
+    /// Sub-directory of the repository containing the application.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+}
+
+fn is_unlimited(resources: &Resources) -> bool {
+    *resources == Resources::default()
+}
+
+impl Manifest {
+    /// Reads and validates a manifest file.
+    pub fn load(path: &Path) -> Result<Self> {
+        let content = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read manifest {}", path.display()))?;
+        let manifest: Manifest = toml::from_str(&content)
+            .with_context(|| format!("Invalid manifest {}", path.display()))?;
+
+        if manifest.name.trim().is_empty() {
+            bail!("The manifest {} has an empty name", path.display());
+        }
+        if manifest.replicas == 0 {
+            bail!(
+                "The manifest {} must request at least one replica",
+                path.display()
+            );
+        }
+
+        Ok(manifest)
+    }
+
+    /// Writes the manifest to a file.
+    pub fn save(&self, path: &Path) -> Result<()> {
+        let content = toml::to_string_pretty(self)?;
+        fs::write(path, content).with_context(|| format!("Failed to write {}", path.display()))
+    }
+
+    /// Builds the desired application state described by the manifest.
+    ///
+    /// # Arguments
+    ///
+    /// * `manifest_path` - The path the manifest was loaded from, used to detect
+    ///   the application type when it is not set
+    pub fn to_spec(&self, manifest_path: &Path) -> Result<AppSpec> {
+        let app_type = match self.app_type {
+            Some(app_type) => app_type,
+            None => AppType::detect(&self.project_dir(manifest_path))?,
+        };
+
+        Ok(AppSpec {
+            app_name: self.name.clone(),
+            app_type: app_type.as_str().to_string(),
+            github_url: self.source.github_url.clone(),
+            git_ref: self.source.git_ref.clone(),
+            path: self.source.path.clone(),
+            env: self.env.clone(),
+            replicas: self.replicas,
+            domains: self.domains.clone(),
+            resources: self.resources.clone(),
+        })
+    }
+
+    /// Returns the local directory holding the application sources.
+    fn project_dir(&self, manifest_path: &Path) -> PathBuf {
+        let root = manifest_path
+            .parent()
+            .filter(|dir| !dir.as_os_str().is_empty())
+            .unwrap_or(Path::new("."));
+        match &self.source.path {
+            Some(path) => root.join(path),
+            None => root.to_path_buf(),
+        }
+    }
+}
@@ -1 +1,5 @@
+pub mod app_spec;
+pub mod app_type;
 pub mod cli;
+pub mod manifest;
+pub mod role;
@@ -0,0 +1,34 @@
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// Role of a user, on the whole platform or on a single application.
+///
+/// Each role includes the permissions of the ones before it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Role {
+    /// Can list applications and read their status, logs and configuration
+    Viewer,
+    /// Can also create, deploy, scale and restart applications
+    Deployer,
+    /// Can also remove applications and manage users
+    Admin,
+}
+
+impl Role {
+    /// Returns the name of the role as expected by the server.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Role::Viewer => "viewer",
+            Role::Deployer => "deployer",
+            Role::Admin => "admin",
+        }
+    }
+}
+
+impl fmt::Display for Role {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
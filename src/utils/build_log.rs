@@ -0,0 +1,121 @@
+use crate::api::build::BuildEvent;
+use anyhow::{anyhow, Result};
+use console::style;
+use indicatif::ProgressBar;
+use std::fs;
+use std::path::PathBuf;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Renders streamed build events under a spinner and keeps the full log.
+///
+/// Steps replace the spinner message, log lines are printed above it. When the
+/// build fails, the full log is written to a file so it can be inspected later.
+pub struct BuildLog {
+    app_name: String,
+    spinner: ProgressBar,
+    lines: Vec<String>,
+    outcome: Option<Result<Option<String>, String>>,
+    dir: PathBuf,
+}
+
+impl BuildLog {
+    pub fn new(app_name: &str, spinner: ProgressBar) -> Self {
+        let dir = dirs::cache_dir()
+            .unwrap_or_else(std::env::temp_dir)
+            .join("nephelios")
+            .join("builds");
+        Self::in_dir(app_name, spinner, dir)
+    }
+
+    /// Creates a log saved in `dir` when the build fails, instead of the user cache directory.
+    pub fn in_dir(app_name: &str, spinner: ProgressBar, dir: PathBuf) -> Self {
+        Self {
+            app_name: app_name.to_string(),
+            spinner,
+            lines: vec![],
+            outcome: None,
+            dir,
+        }
+    }
+
+    /// Renders one event and records it in the log.
+    pub fn handle(&mut self, event: BuildEvent) {
+        match event {
+            BuildEvent::Step { name } => {
+                self.spinner
+                    .println(format!("{} {}", style("▶").cyan(), name));
+                self.spinner.set_message(name.clone());
+                self.lines.push(format!("==> {}", name));
+            }
+            BuildEvent::Log { line } => {
+                self.spinner
+                    .println(format!("   {} {}", style("│").dim(), line));
+                self.lines.push(line);
+            }
+            BuildEvent::Done { message } => {
+                self.outcome = Some(Ok(message));
+            }
+            BuildEvent::Error { message } => {
+                self.lines.push(format!("ERROR: {}", message));
+                self.outcome = Some(Err(message));
+            }
+        }
+    }
+
+    /// Clears the spinner when the build is no longer followed.
+    pub fn detach(&self) {
+        self.spinner.finish_and_clear();
+    }
+
+    /// Clears the spinner and returns how the build ended.
+    ///
+    /// # Arguments
+    ///
+    /// * `streamed` - The result of reading the build stream
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Option<String>)` with the final server message if the build succeeded
+    /// * `Err(anyhow::Error)` if the build failed or the stream could not be read
+    ///   to the end, mentioning where the full log was saved
+    pub fn finish(&self, streamed: Result<()>) -> Result<Option<String>> {
+        self.spinner.finish_and_clear();
+
+        let error = match (streamed, &self.outcome) {
+            (Err(e), _) => format!("{:#}", e),
+            (Ok(()), Some(Ok(message))) => return Ok(message.clone()),
+            (Ok(()), Some(Err(message))) => format!("Build failed: {}", message),
+            (Ok(()), None) => "The build stream ended before the build completed".to_string(),
+        };
+
+        match self.save() {
+            Ok(path) => Err(anyhow!(
+                "{}\nFull build log saved to {}",
+                error,
+                path.display()
+            )),
+            Err(e) => Err(anyhow!(
+                "{}\nThe build log could not be saved: {}",
+                error,
+                e
+            )),
+        }
+    }
+
+    /// Writes the full log to the log directory, the user cache directory by default.
+    fn save(&self) -> Result<PathBuf> {
+        fs::create_dir_all(&self.dir)?;
+
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |elapsed| elapsed.as_secs());
+        let path = self
+            .dir
+            .join(format!("{}-{}.log", self.app_name, timestamp));
+
+        let mut content = self.lines.join("\n");
+        content.push('\n');
+        fs::write(&path, content)?;
+        Ok(path)
+    }
+}
@@ -0,0 +1,229 @@
+use crate::utils::credentials::load_git_token;
+use anyhow::{Context as _, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::env;
+use std::fs;
+use std::io::Write as _;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+/// Name of the context used when none has been selected.
+pub const DEFAULT_CONTEXT: &str = "default";
+
+/// Context and TLS options selected on the command line for the whole run.
+static SELECTION: OnceLock<(Option<String>, TlsOptions)> = OnceLock::new();
+
+/// Persistent CLI configuration, stored as TOML in the user's config directory.
+///
+/// A configuration holds any number of named contexts, each describing how to
+/// reach and authenticate against one Nephelios installation.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct Config {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub current_context: Option<String>,
+
+    #[serde(default)]
+    pub contexts: BTreeMap<String, ContextConfig>,
+}
+
+/// Settings stored for a single named context.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct ContextConfig {
+    /// Token used to clone private repositories over HTTPS.
+    ///
+    /// It is kept in the system keyring rather than in the configuration file,
+    /// and only loaded by [`Config::active_with_git_token`].
+    #[serde(skip)]
+    pub git_token: Option<String>,
+
+    /// Path to a private key used to clone repositories over SSH.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub git_ssh_key: Option<PathBuf>,
+
+    /// Domain under which applications get their default host name (e.g., apps.example.com).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub domain_suffix: Option<String>,
+
+    /// ACME directory used to request certificates (e.g., a local Pebble for testing).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub acme_directory: Option<String>,
+
+    /// Contact address registered with the ACME directory.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub acme_email: Option<String>,
+
+    /// Issuer URL of the identity provider used by `login --device`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub oidc_issuer: Option<String>,
+
+    /// OAuth client registered for the CLI at the identity provider.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub oidc_client_id: Option<String>,
+
+    /// Proxy used to reach the server, instead of the one in `HTTPS_PROXY`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub proxy: Option<String>,
+
+    /// Comma-separated hosts reached without the proxy of the context.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub no_proxy: Option<String>,
+
+    /// How to secure the connection to the server.
+    #[serde(default, flatten)]
+    pub tls: TlsOptions,
+}
+
+/// TLS settings of the connection to the Nephelios server.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TlsOptions {
+    /// PEM bundle of CA certificates trusted in addition to the system ones.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ca_cert: Option<PathBuf>,
+
+    /// PEM certificate presented to the server for mutual TLS.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub client_cert: Option<PathBuf>,
+
+    /// PKCS#8 PEM private key of the client certificate.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub client_key: Option<PathBuf>,
+
+    /// Accept any server certificate. Only meant for testing.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub insecure_skip_verify: Option<bool>,
+}
+
+impl TlsOptions {
+    /// Returns these options with the ones set in `overrides` taking precedence.
+    pub fn merge(self, overrides: &TlsOptions) -> TlsOptions {
+        TlsOptions {
+            ca_cert: overrides.ca_cert.clone().or(self.ca_cert),
+            client_cert: overrides.client_cert.clone().or(self.client_cert),
+            client_key: overrides.client_key.clone().or(self.client_key),
+            insecure_skip_verify: overrides.insecure_skip_verify.or(self.insecure_skip_verify),
+        }
+    }
+
+    /// Whether server certificates are accepted without verification.
+    pub fn skips_verification(&self) -> bool {
+        self.insecure_skip_verify == Some(true)
+    }
+}
+
+impl Config {
+    /// Returns the location of the configuration file.
+    ///
+    /// `NEPHELIOS_CONFIG` takes precedence over the platform config directory.
+    pub fn path() -> Result<PathBuf> {
+        if let Ok(path) = env::var("NEPHELIOS_CONFIG") {
+            return Ok(PathBuf::from(path));
+        }
+
+        let dir = dirs::config_dir().context("Could not determine the user config directory")?;
+        Ok(dir.join("nephelios").join("config.toml"))
+    }
+
+    /// Loads the configuration, returning an empty one if the file does not exist yet.
+    pub fn load() -> Result<Self> {
+        let path = Self::path()?;
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+
+        let content = fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        toml::from_str(&content)
+            .with_context(|| format!("Invalid configuration in {}", path.display()))
+    }
+
+    /// Writes the configuration back to disk.
+    ///
+    /// The file may contain credentials, so it is only readable by the current user.
+    pub fn save(&self) -> Result<()> {
+        let path = Self::path()?;
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)
+                .with_context(|| format!("Failed to create {}", parent.display()))?;
+        }
+
+        write_private(&path, &toml::to_string_pretty(self)?)
+    }
+
+    /// Records the context and TLS options selected on the command line, so that
+    /// code which is not handed the selection explicitly (such as the API client)
+    /// uses it too.
+    pub fn select(selected: Option<&str>, tls: TlsOptions) {
+        SELECTION.set((selected.map(str::to_string), tls)).ok();
+    }
+
+    /// Returns the context recorded with [`Config::select`], if any.
+    pub fn selected() -> Option<&'static str> {
+        SELECTION
+            .get()
+            .and_then(|(selected, _)| selected.as_deref())
+    }
+
+    /// Resolves the name of the active context.
+    ///
+    /// An explicit selection (`--context` or `NEPHELIOS_CONTEXT`) wins over the
+    /// one stored with `context use`.
+    pub fn active_name(&self, selected: Option<&str>) -> String {
+        selected
+            .map(str::to_string)
+            .or_else(|| self.current_context.clone())
+            .unwrap_or_else(|| DEFAULT_CONTEXT.to_string())
+    }
+
+    /// Returns the settings of the active context, or empty settings if it was never configured.
+    ///
+    /// TLS options given on the command line override the ones stored in the context.
+    pub fn active(&self, selected: Option<&str>) -> ContextConfig {
+        let mut context = self
+            .contexts
+            .get(&self.active_name(selected))
+            .cloned()
+            .unwrap_or_default();
+        if let Some((_, overrides)) = SELECTION.get() {
+            context.tls = context.tls.merge(overrides);
+        }
+        context
+    }
+
+    /// Returns the settings of the active context along with its stored Git token.
+    pub fn active_with_git_token(&self, selected: Option<&str>) -> Result<ContextConfig> {
+        let mut context = self.active(selected);
+        context.git_token = load_git_token(&self.active_name(selected))?;
+        Ok(context)
+    }
+}
+
+/// Writes a file that only the current user may read, as it may contain credentials.
+///
+/// The file is created with restricted permissions, and an existing file is
+/// restricted before anything is written to it.
+///
+/// # Arguments
+///
+/// * `path` - The path of the file
+/// * `content` - The new content of the file
+pub fn write_private(path: &Path, content: &str) -> Result<()> {
+    let mut options = fs::OpenOptions::new();
+    options.write(true).create(true).truncate(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        options.mode(0o600);
+    }
+
+    let mut file = options
+        .open(path)
+        .with_context(|| format!("Failed to write {}", path.display()))?;
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        file.set_permissions(fs::Permissions::from_mode(0o600))?;
+    }
+    file.write_all(content.as_bytes())
+        .with_context(|| format!("Failed to write {}", path.display()))
+}
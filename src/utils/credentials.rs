@@ -0,0 +1,203 @@
+use crate::utils::config::{write_private, Config};
+use anyhow::{Context as _, Result};
+use keyring::Entry;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::PathBuf;
+
+/// Service name under which sessions are stored in the system keyring.
+const KEYRING_SERVICE: &str = "nephelios-cli";
+
+/// Service name under which Git tokens are stored in the system keyring.
+const GIT_KEYRING_SERVICE: &str = "nephelios-cli-git";
+
+/// Credentials a context is logged in with.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Session {
+    /// API token sent as a bearer token.
+    pub token: String,
+
+    /// Token used to obtain a new API token when it expires, for SSO logins.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub refresh_token: Option<String>,
+}
+
+/// Where a session was stored.
+pub enum SessionStore {
+    Keyring,
+    File(PathBuf),
+}
+
+/// Sessions and Git tokens of the contexts for which no system keyring is available.
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct CredentialsFile {
+    #[serde(default)]
+    sessions: BTreeMap<String, Session>,
+
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    git_tokens: BTreeMap<String, String>,
+}
+
+impl CredentialsFile {
+    /// Returns the location of the fallback file, next to the configuration file.
+    fn path() -> Result<PathBuf> {
+        let config = Config::path()?;
+        Ok(config.with_file_name("credentials.toml"))
+    }
+
+    fn load() -> Result<Self> {
+        let path = Self::path()?;
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+
+        let content = fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        toml::from_str(&content)
+            .with_context(|| format!("Invalid credentials in {}", path.display()))
+    }
+
+    /// Writes the file back to disk, only readable by the current user.
+    fn save(&self) -> Result<PathBuf> {
+        let path = Self::path()?;
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)
+                .with_context(|| format!("Failed to create {}", parent.display()))?;
+        }
+
+        write_private(&path, &toml::to_string_pretty(self)?)?;
+        Ok(path)
+    }
+}
+
+/// Stores the session of a context, in the system keyring when one is available.
+///
+/// # Arguments
+///
+/// * `context` - The name of the context
+/// * `session` - The credentials to store
+///
+/// # Returns
+///
+/// * `Ok(SessionStore)` telling where the session was stored
+/// * `Err(anyhow::Error)` if neither the keyring nor the fallback file could be written
+pub fn store_session(context: &str, session: &Session) -> Result<SessionStore> {
+    let value = serde_json::to_string(session)?;
+    if Entry::new(KEYRING_SERVICE, context)
+        .and_then(|entry| entry.set_password(&value))
+        .is_ok()
+    {
+        // Drop any session left in the fallback file by an earlier login
+        remove_from_file(context)?;
+        return Ok(SessionStore::Keyring);
+    }
+
+    let mut file = CredentialsFile::load()?;
+    file.sessions.insert(context.to_string(), session.clone());
+    Ok(SessionStore::File(file.save()?))
+}
+
+/// Returns the session of a context, if it is logged in.
+///
+/// # Arguments
+///
+/// * `context` - The name of the context
+pub fn load_session(context: &str) -> Result<Option<Session>> {
+    if let Ok(value) = Entry::new(KEYRING_SERVICE, context).and_then(|entry| entry.get_password()) {
+        return Ok(serde_json::from_str(&value).ok());
+    }
+
+    Ok(CredentialsFile::load()?.sessions.remove(context))
+}
+
+/// Forgets the session of a context.
+///
+/// # Arguments
+///
+/// * `context` - The name of the context
+///
+/// # Returns
+///
+/// * `Ok(true)` if a session was stored
+/// * `Ok(false)` if the context was not logged in
+pub fn delete_session(context: &str) -> Result<bool> {
+    let in_keyring = Entry::new(KEYRING_SERVICE, context)
+        .and_then(|entry| entry.delete_credential())
+        .is_ok();
+    let in_file = remove_from_file(context)?;
+    Ok(in_keyring || in_file)
+}
+
+fn remove_from_file(context: &str) -> Result<bool> {
+    let mut file = CredentialsFile::load()?;
+    if file.sessions.remove(context).is_none() {
+        return Ok(false);
+    }
+    file.save()?;
+    Ok(true)
+}
+
+/// Stores the token used to clone private repositories of a context, in the
+/// system keyring when one is available.
+///
+/// # Arguments
+///
+/// * `context` - The name of the context
+/// * `token` - The Git access token
+///
+/// # Returns
+///
+/// * `Ok(SessionStore)` telling where the token was stored
+/// * `Err(anyhow::Error)` if neither the keyring nor the fallback file could be written
+pub fn store_git_token(context: &str, token: &str) -> Result<SessionStore> {
+    if Entry::new(GIT_KEYRING_SERVICE, context)
+        .and_then(|entry| entry.set_password(token))
+        .is_ok()
+    {
+        remove_git_token_from_file(context)?;
+        return Ok(SessionStore::Keyring);
+    }
+
+    let mut file = CredentialsFile::load()?;
+    file.git_tokens
+        .insert(context.to_string(), token.to_string());
+    Ok(SessionStore::File(file.save()?))
+}
+
+/// Returns the Git token stored for a context, if any.
+///
+/// # Arguments
+///
+/// * `context` - The name of the context
+pub fn load_git_token(context: &str) -> Result<Option<String>> {
+    if let Ok(token) =
+        Entry::new(GIT_KEYRING_SERVICE, context).and_then(|entry| entry.get_password())
+    {
+        return Ok(Some(token));
+    }
+
+    Ok(CredentialsFile::load()?.git_tokens.remove(context))
+}
+
+/// Forgets the Git token of a context.
+///
+/// # Arguments
+///
+/// * `context` - The name of the context
+pub fn delete_git_token(context: &str) -> Result<()> {
+    Entry::new(GIT_KEYRING_SERVICE, context)
+        .and_then(|entry| entry.delete_credential())
+        .ok();
+    remove_git_token_from_file(context)?;
+    Ok(())
+}
+
+fn remove_git_token_from_file(context: &str) -> Result<bool> {
+    let mut file = CredentialsFile::load()?;
+    if file.git_tokens.remove(context).is_none() {
+        return Ok(false);
+    }
+    file.save()?;
+    Ok(true)
+}
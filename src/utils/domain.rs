@@ -0,0 +1,73 @@
+use anyhow::{bail, Result};
+
+/// Turns a host name given on the command line into a fully qualified one.
+///
+/// A single label (e.g., `api`) is completed with the domain suffix of the
+/// active context, so that `api` becomes `api.apps.example.com`. Host names
+/// are case-insensitive and returned in lowercase.
+///
+/// # Arguments
+///
+/// * `host` - The host name, either fully qualified or a single label
+/// * `suffix` - The domain suffix of the active context, if any
+///
+/// # Returns
+///
+/// * `Ok(String)` with the fully qualified host name
+/// * `Err(anyhow::Error)` if the host name is invalid, or is a single label without a suffix to complete it
+pub fn qualify_host(host: &str, suffix: Option<&str>) -> Result<String> {
+    let host = host.trim().trim_end_matches('.').to_ascii_lowercase();
+
+    let host = if host.contains('.') {
+        host
+    } else {
+        let Some(suffix) = suffix else {
+            bail!(
+                "'{}' is not a fully qualified host name. Use a name such as {}.example.com, \
+                 or set a domain suffix with `context set <name> --domain-suffix <suffix>`",
+                host,
+                host
+            );
+        };
+        format!("{}.{}", host, suffix.trim_matches('.'))
+    };
+
+    validate_host(&host)?;
+    Ok(host)
+}
+
+/// Normalizes a domain suffix, which may be a single label (e.g., `localhost`).
+///
+/// # Arguments
+///
+/// * `suffix` - The domain suffix, such as `apps.example.com`
+///
+/// # Returns
+///
+/// * `Ok(String)` with the suffix in lowercase, without leading or trailing dots
+/// * `Err(anyhow::Error)` if the suffix is not made of valid DNS labels
+pub fn normalize_suffix(suffix: &str) -> Result<String> {
+    let suffix = suffix.trim().trim_matches('.').to_ascii_lowercase();
+    validate_host(&suffix)?;
+    Ok(suffix)
+}
+
+/// Checks that a host name is made of valid DNS labels.
+fn validate_host(host: &str) -> Result<()> {
+    if host.len() > 253 {
+        bail!("Host name {} is longer than 253 characters", host);
+    }
+
+    for label in host.split('.') {
+        let valid = !label.is_empty()
+            && label.len() <= 63
+            && !label.starts_with('-')
+            && !label.ends_with('-')
+            && label.chars().all(|c| c.is_ascii_alphanumeric() || c == '-');
+        if !valid {
+            bail!("'{}' is not a valid host name", host);
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,84 @@
+use anyhow::{bail, Context as _, Result};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+/// Reads the variables defined in a `.env` file.
+///
+/// Lines are `KEY=value` pairs, optionally prefixed with `export`, with `#`
+/// comments and single or double quoted values. Values are taken literally:
+/// `${VAR}` is not expanded, so variables of the local environment never leak
+/// into the application.
+///
+/// # Arguments
+///
+/// * `path` - The path of the `.env` file
+pub fn read_env_file(path: &Path) -> Result<BTreeMap<String, String>> {
+    let content =
+        fs::read_to_string(path).with_context(|| format!("Failed to read {}", path.display()))?;
+
+    let mut vars = BTreeMap::new();
+    for (number, line) in content.lines().enumerate() {
+        let parsed = parse_line(line).with_context(|| {
+            format!("Invalid .env file {}, line {}", path.display(), number + 1)
+        })?;
+        if let Some((key, value)) = parsed {
+            vars.insert(key, value);
+        }
+    }
+    Ok(vars)
+}
+
+/// Parses one line of a `.env` file, returning `None` for blank lines and comments.
+fn parse_line(line: &str) -> Result<Option<(String, String)>> {
+    let line = line.trim();
+    if line.is_empty() || line.starts_with('#') {
+        return Ok(None);
+    }
+    let line = line.strip_prefix("export ").map_or(line, str::trim_start);
+
+    let Some((key, value)) = line.split_once('=') else {
+        bail!("expected KEY=value, found '{}'", line);
+    };
+    let key = key.trim();
+    if key.is_empty()
+        || !key
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '.'))
+    {
+        bail!("invalid variable name '{}'", key);
+    }
+
+    Ok(Some((key.to_string(), parse_value(value.trim())?)))
+}
+
+/// Parses the value of a variable, removing its quotes or its trailing comment.
+fn parse_value(value: &str) -> Result<String> {
+    let Some(quote) = value.chars().next().filter(|c| matches!(c, '"' | '\'')) else {
+        let value = value.split_once(" #").map_or(value, |(value, _)| value);
+        return Ok(value.trim_end().to_string());
+    };
+
+    let mut parsed = String::new();
+    let mut chars = value[1..].chars();
+    loop {
+        match chars.next() {
+            None => bail!("unterminated quoted value {}", value),
+            Some(c) if c == quote => break,
+            // Double quoted values support the usual escapes, single quoted ones are literal
+            Some('\\') if quote == '"' => match chars.next() {
+                Some('n') => parsed.push('\n'),
+                Some('t') => parsed.push('\t'),
+                Some(c) => parsed.push(c),
+                None => bail!("unterminated quoted value {}", value),
+            },
+            Some(c) => parsed.push(c),
+        }
+    }
+
+    let rest = chars.as_str().trim();
+    if !rest.is_empty() && !rest.starts_with('#') {
+        bail!("unexpected characters after the quoted value: '{}'", rest);
+    }
+    Ok(parsed)
+}
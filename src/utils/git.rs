@@ -0,0 +1,150 @@
+use crate::utils::config::ContextConfig;
+use anyhow::{bail, Context as _, Result};
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+/// Transport used to clone a repository.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RepoTransport {
+    Https,
+    Ssh,
+}
+
+/// Validates a repository URL and returns the transport it uses.
+///
+/// Accepted forms are `https://host/owner/repo`, `git@host:owner/repo` and
+/// `ssh://git@host/owner/repo`, with or without a trailing `.git`.
+pub fn parse_repo_url(url: &str) -> Result<RepoTransport> {
+    if let Some(rest) = url.strip_prefix("https://") {
+        if has_owner_and_repo(rest.split_once('/').map_or("", |(_, path)| path)) {
+            return Ok(RepoTransport::Https);
+        }
+    } else if let Some(rest) = url.strip_prefix("ssh://") {
+        if has_owner_and_repo(rest.split_once('/').map_or("", |(_, path)| path)) {
+            return Ok(RepoTransport::Ssh);
+        }
+    } else if let Some((user_host, path)) = url.split_once(':') {
+        if user_host.contains('@') && !user_host.contains('/') && has_owner_and_repo(path) {
+            return Ok(RepoTransport::Ssh);
+        }
+    }
+
+    bail!(
+        "Invalid repository URL '{}'. Expected https://github.com/<owner>/<repo> or git@github.com:<owner>/<repo>.git",
+        url
+    )
+}
+
+fn has_owner_and_repo(path: &str) -> bool {
+    let mut segments = path.trim_end_matches('/').split('/');
+    matches!(
+        (segments.next(), segments.next()),
+        (Some(owner), Some(repo)) if !owner.is_empty() && !repo.is_empty()
+    )
+}
+
+/// Credentials sent to the server so it can clone a private repository.
+#[derive(Debug, Default)]
+pub struct GitCredentials {
+    pub token: Option<String>,
+    pub ssh_key: Option<String>,
+}
+
+impl GitCredentials {
+    /// Picks the credentials matching the repository transport.
+    ///
+    /// Flags given on the command line must match the transport, while
+    /// credentials stored in the context are only used when they apply.
+    pub fn resolve(
+        transport: RepoTransport,
+        token: Option<&str>,
+        ssh_key: Option<&Path>,
+        context: &ContextConfig,
+    ) -> Result<Self> {
+        match transport {
+            RepoTransport::Https => {
+                if ssh_key.is_some() {
+                    bail!("--git-ssh-key can only be used with an SSH repository URL (git@host:owner/repo)");
+                }
+                Ok(Self {
+                    token: token
+                        .map(str::to_string)
+                        .or_else(|| context.git_token.clone()),
+                    ssh_key: None,
+                })
+            }
+            RepoTransport::Ssh => {
+                if token.is_some() {
+                    bail!("--git-token can only be used with an HTTPS repository URL");
+                }
+                let ssh_key = match ssh_key.or(context.git_ssh_key.as_deref()) {
+                    Some(path) => Some(read_ssh_key(path)?),
+                    None => None,
+                };
+                Ok(Self {
+                    token: None,
+                    ssh_key,
+                })
+            }
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.token.is_none() && self.ssh_key.is_none()
+    }
+}
+
+fn read_ssh_key(path: &Path) -> Result<String> {
+    let key = fs::read_to_string(path)
+        .with_context(|| format!("Failed to read SSH key {}", path.display()))?;
+    if !key.contains("PRIVATE KEY") {
+        bail!("{} does not look like a private key", path.display());
+    }
+    Ok(key)
+}
+
+/// Refuses to send credentials in clear text to a remote server.
+///
+/// Plain HTTP is only tolerated for loopback addresses, where the traffic
+/// never leaves the machine. This covers Git credentials as well as private
+/// keys of uploaded certificates.
+pub fn ensure_secure_transport(server_url: &str) -> Result<()> {
+    let Some(rest) = server_url.strip_prefix("http://") else {
+        return Ok(());
+    };
+
+    let host = rest.split('/').next().unwrap_or_default();
+    let host = match host.strip_prefix('[') {
+        Some(ipv6) => ipv6.split(']').next().unwrap_or_default(),
+        None => host.split(':').next().unwrap_or_default(),
+    };
+    if matches!(host, "localhost" | "127.0.0.1" | "::1") {
+        return Ok(());
+    }
+
+    bail!(
+        "Refusing to send credentials to {} over plain HTTP. Use an https:// NEPHELIOS_URL.",
+        server_url
+    )
+}
+
+/// Returns the URL of the `origin` remote of a local Git repository, if any.
+///
+/// # Arguments
+///
+/// * `dir` - A directory inside the repository
+pub fn origin_url(dir: &Path) -> Option<String> {
+    let output = Command::new("git")
+        .args(["remote", "get-url", "origin"])
+        .current_dir(dir)
+        .output()
+        .ok()?;
+
+    if !output.status.success() {
+        return None;
+    }
+
+    let url = String::from_utf8(output.stdout).ok()?.trim().to_string();
+    (!url.is_empty()).then_some(url)
+}
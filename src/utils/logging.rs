@@ -0,0 +1,131 @@
+use anyhow::{Context as _, Result};
+use serde_json::Value;
+use std::fs::OpenOptions;
+use std::io::IsTerminal;
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+use tracing::level_filters::LevelFilter;
+use tracing_subscriber::filter::Targets;
+use tracing_subscriber::fmt::writer::BoxMakeWriter;
+use tracing_subscriber::layer::SubscriberExt;
+use tracing_subscriber::util::SubscriberInitExt;
+
+/// Longest body logged with `-vvv`, in bytes.
+const MAX_LOGGED_BODY: usize = 4096;
+
+/// Whether logs are written to stderr, where they would garble the spinners.
+static LOGS_TO_STDERR: AtomicBool = AtomicBool::new(false);
+
+/// Sets up logging for the given verbosity.
+///
+/// * `-v` logs the method, URL, status and duration of every API request
+/// * `-vv` also logs the request and response headers, with credentials hidden
+/// * `-vvv` also logs the request and response bodies, with secrets redacted
+///
+/// Logs go to stderr, or to `log_file` when given. A log file records at least
+/// the headers, even without `-v`.
+///
+/// # Arguments
+///
+/// * `verbosity` - The number of `-v` flags
+/// * `log_file` - The file to append the logs to, if any
+pub fn init(verbosity: u8, log_file: Option<&Path>) -> Result<()> {
+    let verbosity = match log_file {
+        Some(_) => verbosity.max(2),
+        None => verbosity,
+    };
+    let level = match verbosity {
+        0 => return Ok(()),
+        1 => LevelFilter::INFO,
+        2 => LevelFilter::DEBUG,
+        _ => LevelFilter::TRACE,
+    };
+
+    let (writer, ansi) = match log_file {
+        Some(path) => {
+            let file = OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(path)
+                .with_context(|| format!("Failed to open log file {}", path.display()))?;
+            (BoxMakeWriter::new(Mutex::new(file)), false)
+        }
+        None => {
+            LOGS_TO_STDERR.store(true, Ordering::Relaxed);
+            (
+                BoxMakeWriter::new(std::io::stderr),
+                std::io::stderr().is_terminal(),
+            )
+        }
+    };
+
+    tracing_subscriber::registry()
+        .with(
+            tracing_subscriber::fmt::layer()
+                .with_writer(writer)
+                .with_ansi(ansi)
+                .with_target(false),
+        )
+        .with(Targets::new().with_target(env!("CARGO_CRATE_NAME"), level))
+        .try_init()
+        .ok();
+    Ok(())
+}
+
+/// Whether logs are written to stderr, in which case spinners are hidden.
+pub fn logs_to_stderr() -> bool {
+    LOGS_TO_STDERR.load(Ordering::Relaxed)
+}
+
+/// Whether a JSON field holds a credential or a secret value.
+fn is_sensitive(field: &str) -> bool {
+    let field = field.to_ascii_lowercase();
+    ["token", "secret", "password", "key", "credential"]
+        .iter()
+        .any(|sensitive| field.contains(sensitive))
+        || matches!(field.as_str(), "env" | "vars")
+}
+
+fn redact_value(value: &mut Value) {
+    match value {
+        Value::Object(fields) => {
+            for (field, value) in fields.iter_mut() {
+                if is_sensitive(field) && !value.is_null() {
+                    *value = Value::String("********".to_string());
+                } else {
+                    redact_value(value);
+                }
+            }
+        }
+        Value::Array(values) => values.iter_mut().for_each(redact_value),
+        _ => {}
+    }
+}
+
+/// Formats a request or response body for the logs.
+///
+/// Credentials, secrets and environment variables in JSON bodies are replaced
+/// with asterisks. Other bodies are shown as text, and long bodies are truncated.
+///
+/// # Arguments
+///
+/// * `body` - The raw body
+pub fn redact_body(body: &[u8]) -> String {
+    let text = match serde_json::from_slice::<Value>(body) {
+        Ok(mut json) => {
+            redact_value(&mut json);
+            json.to_string()
+        }
+        Err(_) => String::from_utf8_lossy(body).into_owned(),
+    };
+
+    if text.len() <= MAX_LOGGED_BODY {
+        return text;
+    }
+    let mut end = MAX_LOGGED_BODY;
+    while !text.is_char_boundary(end) {
+        end -= 1;
+    }
+    format!("{}... ({} bytes)", &text[..end], text.len())
+}
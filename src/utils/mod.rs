@@ -1 +1,13 @@
+pub mod build_log;
+pub mod config;
+pub mod credentials;
+pub mod domain;
+pub mod env_file;
+pub mod git;
+pub mod logging;
+pub mod plan;
+pub mod rollout;
+pub mod server_url;
 pub mod spinner;
+pub mod version_cache;
+pub mod wait;
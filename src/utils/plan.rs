@@ -0,0 +1,20 @@
+use crate::types::app_spec::FieldChange;
+use console::style;
+
+/// Prints a list of changes, colored by kind.
+///
+/// Additions are shown in green, removals in red and updates in yellow.
+///
+/// # Arguments
+///
+/// * `changes` - The changes to print
+pub fn print_changes(changes: &[FieldChange]) {
+    for change in changes {
+        let line = match (&change.old, &change.new) {
+            (None, Some(_)) => style(change.to_string()).green(),
+            (Some(_), None) => style(change.to_string()).red(),
+            _ => style(change.to_string()).yellow(),
+        };
+        println!("   {}", line);
+    }
+}
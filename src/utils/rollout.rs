@@ -0,0 +1,126 @@
+use crate::api::apps::{AppStatus, UpdateState};
+use crate::api::client::NepheliosClient;
+use crate::utils::spinner::{create_progress_bar, create_spinner};
+use crate::utils::wait::POLL_INTERVAL;
+use anyhow::{bail, Result};
+use std::future::Future;
+use std::time::{Duration, Instant};
+
+/// Requests a rolling update of an application, then waits until it has
+/// replaced every replica.
+///
+/// The spec version of the service is read before the request, so that the
+/// replicas of the previous version are not mistaken for updated ones.
+///
+/// # Arguments
+///
+/// * `client` - The client used to reach the API
+/// * `app_name` - The name of the application
+/// * `message` - The message of the spinner shown while the update is requested
+/// * `action` - Sends the request that starts the rolling update
+/// * `timeout` - How long to wait for the update to complete
+///
+/// # Returns
+///
+/// * `Ok(AppStatus)` with the final status once every replica runs the new version
+/// * `Err(anyhow::Error)` if the request failed, the update was paused or rolled
+///   back, or it did not complete in time
+pub async fn run_and_wait<F, Fut>(
+    client: &NepheliosClient,
+    app_name: &str,
+    message: &str,
+    action: F,
+    timeout: Duration,
+) -> Result<AppStatus>
+where
+    F: FnOnce() -> Fut,
+    Fut: Future<Output = Result<()>>,
+{
+    let spinner = create_spinner(message);
+    let result = async {
+        let before = client.app_status(app_name).await?;
+        action().await?;
+        Ok::<_, anyhow::Error>(before.version)
+    }
+    .await;
+    spinner.finish_and_clear();
+    let since = result?;
+
+    wait_for_rollout(client, app_name, since, timeout).await
+}
+
+/// Waits until a rolling update of an application has replaced every replica.
+///
+/// Unlike [`wait_for_replicas`](crate::utils::wait::wait_for_replicas), this
+/// ignores the tasks of the previous version, which keep running while the
+/// update is in progress.
+///
+/// # Arguments
+///
+/// * `client` - The client used to poll the application status
+/// * `app_name` - The name of the application
+/// * `since` - The spec version of the service before the update was requested
+/// * `timeout` - How long to wait before giving up
+///
+/// # Returns
+///
+/// * `Ok(AppStatus)` with the final status once every replica runs the new version
+/// * `Err(anyhow::Error)` if the update was paused or rolled back, the timeout
+///   expired, or the status could not be fetched
+pub async fn wait_for_rollout(
+    client: &NepheliosClient,
+    app_name: &str,
+    since: Option<u64>,
+    timeout: Duration,
+) -> Result<AppStatus> {
+    let started = Instant::now();
+    let bar = create_progress_bar(0, &format!("Updating {} tasks", app_name));
+
+    loop {
+        let status = match client.app_status(app_name).await {
+            Ok(status) => status,
+            Err(e) => {
+                bar.finish_and_clear();
+                return Err(e);
+            }
+        };
+
+        bar.set_length(status.replicas as u64);
+        bar.set_position(status.updated_replicas().min(status.replicas) as u64);
+
+        if status.is_rolled_out(since) {
+            bar.finish_and_clear();
+            return Ok(status);
+        }
+
+        if status.is_update_aborted() {
+            bar.finish_and_clear();
+            let outcome = match status.update_state {
+                Some(UpdateState::Paused) => "was paused",
+                _ => "was rolled back",
+            };
+            bail!(
+                "The update of {} {}: {}",
+                app_name,
+                outcome,
+                status
+                    .update_message
+                    .as_deref()
+                    .unwrap_or("no reason given")
+            );
+        }
+
+        if started.elapsed() >= timeout {
+            bar.finish_and_clear();
+            bail!(
+                "Timed out after {}s: {}/{} tasks of {} run the new version",
+                timeout.as_secs(),
+                status.updated_replicas(),
+                status.replicas,
+                app_name
+            );
+        }
+
+        tokio::time::sleep(POLL_INTERVAL).await;
+    }
+}
@@ -0,0 +1,142 @@
+use anyhow::{bail, Result};
+use std::path::PathBuf;
+use url::Url;
+
+/// Server used when `NEPHELIOS_URL` is not set.
+pub const DEFAULT_URL: &str = "http://localhost";
+
+/// Base URL of the requests sent over a Unix domain socket, which only use its path.
+pub const UNIX_BASE_URL: &str = "http://localhost/";
+
+/// Port used when neither `NEPHELIOS_URL` nor `NEPHELIOS_PORT` is set.
+pub const DEFAULT_PORT: u16 = 3030;
+
+/// Builds the base URL of the Nephelios API from `NEPHELIOS_URL` and `NEPHELIOS_PORT`.
+///
+/// The URL may carry a port and a path prefix, for servers behind a reverse
+/// proxy (e.g., `https://example.com/nephelios`). A bare `http://host` URL
+/// without a port reaches the server on `NEPHELIOS_PORT`, or else on port 3030;
+/// https URLs and URLs with a path prefix use the default port of their scheme.
+///
+/// # Arguments
+///
+/// * `url` - The value of `NEPHELIOS_URL`, if set
+/// * `port` - The value of `NEPHELIOS_PORT`, if set
+///
+/// # Returns
+///
+/// * `Ok(Url)` - The base URL, always ending with a slash so that endpoints can be joined to it
+/// * `Err(anyhow::Error)` - If the URL or the port is invalid, or they contradict each other
+pub fn parse_server_url(url: Option<&str>, port: Option<&str>) -> Result<Url> {
+    let raw = url.map(str::trim).filter(|url| !url.is_empty());
+    let mut base = match Url::parse(raw.unwrap_or(DEFAULT_URL)) {
+        Ok(base) => base,
+        Err(e) => bail!(
+            "Invalid NEPHELIOS_URL '{}': {}. Expected a URL such as https://nephelios.example.com",
+            raw.unwrap_or_default(),
+            e
+        ),
+    };
+
+    if !matches!(base.scheme(), "http" | "https") {
+        bail!(
+            "Invalid NEPHELIOS_URL '{}': the scheme must be http or https",
+            base
+        );
+    }
+    if base.host_str().is_none() {
+        bail!("Invalid NEPHELIOS_URL '{}': the host is missing", base);
+    }
+    if base.query().is_some() || base.fragment().is_some() {
+        bail!(
+            "Invalid NEPHELIOS_URL '{}': it must not contain a query or a fragment",
+            base
+        );
+    }
+
+    let port = match port.map(str::trim).filter(|port| !port.is_empty()) {
+        Some(port) => match port.parse::<u16>() {
+            Ok(port) if port != 0 => Some(port),
+            _ => bail!(
+                "Invalid NEPHELIOS_PORT '{}': expected a port number between 1 and 65535",
+                port
+            ),
+        },
+        None => None,
+    };
+
+    // Only a bare http://host points at the API itself; https URLs and path
+    // prefixes go through a reverse proxy on the default port of their scheme.
+    // `Url` forgets ports that are the default of the scheme, such as :443 for https.
+    let bare = base.scheme() == "http" && base.path() == "/";
+    if has_explicit_port(raw.unwrap_or(DEFAULT_URL)) || !bare {
+        let url_port = base.port_or_known_default();
+        if let Some(port) = port.filter(|port| Some(*port) != url_port) {
+            bail!(
+                "NEPHELIOS_URL '{}' uses port {}, which contradicts NEPHELIOS_PORT={}. Put the port in the URL, or unset NEPHELIOS_PORT.",
+                raw.unwrap_or_default(),
+                url_port.unwrap_or_default(),
+                port
+            );
+        }
+    } else if base.set_port(Some(port.unwrap_or(DEFAULT_PORT))).is_err() {
+        bail!("Invalid NEPHELIOS_URL '{}': it cannot have a port", base);
+    }
+
+    if !base.path().ends_with('/') {
+        let path = format!("{}/", base.path());
+        base.set_path(&path);
+    }
+    Ok(base)
+}
+
+/// Whether the authority of a URL spells out a port, even the default one of its scheme.
+fn has_explicit_port(url: &str) -> bool {
+    let authority = url
+        .split_once("://")
+        .map_or(url, |(_, rest)| rest)
+        .split(['/', '?', '#'])
+        .next()
+        .unwrap_or_default();
+    let host = authority
+        .rsplit_once('@')
+        .map_or(authority, |(_, host)| host);
+    let host = host.rsplit_once(']').map_or(host, |(_, after)| after);
+    host.contains(':')
+}
+
+/// Builds the URL of an API endpoint below the base URL, keeping its path prefix.
+///
+/// # Arguments
+///
+/// * `base` - A base URL returned by [`parse_server_url`]
+/// * `path` - The endpoint, with or without a leading slash (e.g., `/apps/my-app`)
+pub fn endpoint_url(base: &Url, path: &str) -> Url {
+    let mut url = base.clone();
+    url.set_path(&format!("{}{}", base.path(), path.trim_start_matches('/')));
+    url
+}
+
+/// Returns the socket of a `unix:///path/to/nephelios.sock` server URL.
+///
+/// # Arguments
+///
+/// * `url` - The value of `NEPHELIOS_URL`, if set
+///
+/// # Returns
+///
+/// * `Ok(Some(path))` - If the URL designates a Unix domain socket
+/// * `Ok(None)` - If the server is reached over TCP
+/// * `Err(anyhow::Error)` - If the socket path is not absolute
+pub fn parse_unix_socket(url: Option<&str>) -> Result<Option<PathBuf>> {
+    let Some(path) = url.and_then(|url| url.trim().strip_prefix("unix://")) else {
+        return Ok(None);
+    };
+    if !path.starts_with('/') {
+        bail!(
+            "Invalid NEPHELIOS_URL 'unix://{}': expected an absolute socket path such as unix:///run/nephelios.sock",
+            path
+        );
+    }
+    Ok(Some(PathBuf::from(path)))
+}
@@ -1,4 +1,10 @@
-use indicatif::{ProgressBar, ProgressStyle};
+use crate::utils::logging::logs_to_stderr;
+use indicatif::{ProgressBar, ProgressDrawTarget, ProgressStyle, WeakProgressBar};
+use std::sync::Mutex;
+
+/// The most recently created spinner, so that code which is not handed the
+/// spinner (such as the API client) can report under it.
+static ACTIVE_SPINNER: Mutex<Option<WeakProgressBar>> = Mutex::new(None);
 
 /// Creates a new spinner with custom styling for loading animations.
 ///
@@ -27,5 +33,78 @@ pub fn create_spinner(message: &str) -> ProgressBar {
     );
     spinner.set_message(message.to_string());
     spinner.enable_steady_tick(std::time::Duration::from_millis(120));
+    hide_while_logging(&spinner);
+    *ACTIVE_SPINNER.lock().unwrap() = Some(spinner.downgrade());
     spinner
 }
+
+/// Shows a note on the line below the active spinner, or on stderr when no
+/// spinner is running.
+///
+/// # Arguments
+///
+/// * `note` - The note to show (e.g., a retry attempt)
+pub fn report_under_spinner(note: &str) {
+    let active = ACTIVE_SPINNER
+        .lock()
+        .unwrap()
+        .as_ref()
+        .and_then(WeakProgressBar::upgrade);
+    match active {
+        Some(spinner) if !spinner.is_finished() => {
+            let message = spinner.message();
+            let message = message.split('\n').next().unwrap_or_default();
+            spinner.set_message(format!("{}\n  ↻ {}", message, note));
+        }
+        _ => eprintln!("↻ {}", note),
+    }
+}
+
+/// Creates a progress bar counting items towards a known total.
+///
+/// # Arguments
+///
+/// * `total` - The number of items to reach
+/// * `message` - The message to display next to the bar
+///
+/// # Returns
+///
+/// * `ProgressBar` - A configured progress bar instance
+pub fn create_progress_bar(total: u64, message: &str) -> ProgressBar {
+    let bar = ProgressBar::new(total);
+    bar.set_style(
+        ProgressStyle::default_bar()
+            .template("{spinner:.green} {msg} [{bar:30.green}] {pos}/{len}")
+            .unwrap()
+            .progress_chars("=> "),
+    );
+    bar.set_message(message.to_string());
+    bar.enable_steady_tick(std::time::Duration::from_millis(120));
+    hide_while_logging(&bar);
+    bar
+}
+
+/// Hides a progress bar when logs are written to stderr, where it would garble them.
+fn hide_while_logging(bar: &ProgressBar) {
+    if logs_to_stderr() {
+        bar.set_draw_target(ProgressDrawTarget::hidden());
+    }
+}
+
+/// Prints a line on stderr, above the active spinner if one is running so that
+/// it is not overwritten.
+///
+/// # Arguments
+///
+/// * `line` - The line to print (e.g., a warning)
+pub fn report_above_spinner(line: &str) {
+    let active = ACTIVE_SPINNER
+        .lock()
+        .unwrap()
+        .as_ref()
+        .and_then(WeakProgressBar::upgrade);
+    match active {
+        Some(spinner) if !spinner.is_finished() => spinner.suspend(|| eprintln!("{}", line)),
+        _ => eprintln!("{}", line),
+    }
+}
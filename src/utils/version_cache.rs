@@ -0,0 +1,103 @@
+use crate::api::version::ServerVersion;
+use crate::utils::config::Config;
+use anyhow::{Context as _, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::PathBuf;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// How long the version of a server is trusted before it is checked again.
+pub const CACHE_TTL: Duration = Duration::from_secs(3600);
+
+/// Versions of the servers the CLI talked to, so that their compatibility is
+/// not checked before every command.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct VersionCache {
+    #[serde(default)]
+    servers: BTreeMap<String, CachedVersion>,
+}
+
+/// Result of the compatibility check of one server.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CachedVersion {
+    /// Version of the CLI that checked the server.
+    cli_version: String,
+
+    /// When the server was checked, in seconds since the Unix epoch.
+    checked_at: u64,
+
+    /// Version reported by the server, unless it predates `GET /version`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    server: Option<ServerVersion>,
+}
+
+impl VersionCache {
+    /// Returns the location of the cache, next to the configuration file.
+    fn path() -> Result<PathBuf> {
+        Ok(Config::path()?.with_file_name("version-cache.toml"))
+    }
+
+    /// Loads the cache, returning an empty one if it is missing or unreadable.
+    pub fn load() -> Self {
+        Self::path()
+            .ok()
+            .and_then(|path| fs::read_to_string(path).ok())
+            .and_then(|content| toml::from_str(&content).ok())
+            .unwrap_or_default()
+    }
+
+    /// Writes the cache back to disk.
+    pub fn save(&self) -> Result<()> {
+        let path = Self::path()?;
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)
+                .with_context(|| format!("Failed to create {}", parent.display()))?;
+        }
+        fs::write(&path, toml::to_string_pretty(self)?)
+            .with_context(|| format!("Failed to write {}", path.display()))
+    }
+
+    /// Returns the version of a server checked recently by this version of the CLI.
+    ///
+    /// # Arguments
+    ///
+    /// * `server` - The address of the server
+    /// * `now` - The current time
+    ///
+    /// # Returns
+    ///
+    /// * `Some(Some(ServerVersion))` if the server was checked recently
+    /// * `Some(None)` if the server was checked recently and does not report its version
+    /// * `None` if the server must be checked again
+    pub fn get(&self, server: &str, now: SystemTime) -> Option<Option<ServerVersion>> {
+        let cached = self.servers.get(server)?;
+        let age = Duration::from_secs(epoch_seconds(now).saturating_sub(cached.checked_at));
+        (cached.cli_version == env!("CARGO_PKG_VERSION") && age < CACHE_TTL)
+            .then(|| cached.server.clone())
+    }
+
+    /// Records the version of a server.
+    ///
+    /// # Arguments
+    ///
+    /// * `server` - The address of the server
+    /// * `version` - The version it reported, if any
+    /// * `now` - The current time
+    pub fn insert(&mut self, server: &str, version: Option<&ServerVersion>, now: SystemTime) {
+        self.servers.insert(
+            server.to_string(),
+            CachedVersion {
+                cli_version: env!("CARGO_PKG_VERSION").to_string(),
+                checked_at: epoch_seconds(now),
+                server: version.cloned(),
+            },
+        );
+    }
+}
+
+fn epoch_seconds(time: SystemTime) -> u64 {
+    time.duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_secs())
+        .unwrap_or_default()
+}
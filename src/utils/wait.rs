@@ -0,0 +1,117 @@
+use crate::api::apps::AppStatus;
+use crate::api::client::NepheliosClient;
+use crate::docker::nephelios_service::NepheliosService;
+use crate::utils::spinner::{create_progress_bar, create_spinner};
+use anyhow::{bail, Result};
+use std::time::{Duration, Instant};
+
+/// Delay between two status checks.
+pub const POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Waits until every replica of an application is running.
+///
+/// A progress bar shows the number of running tasks while waiting.
+///
+/// # Arguments
+///
+/// * `client` - The client used to poll the application status
+/// * `app_name` - The name of the application
+/// * `timeout` - How long to wait before giving up
+///
+/// # Returns
+///
+/// * `Ok(AppStatus)` with the final status once all replicas are running
+/// * `Err(anyhow::Error)` if the timeout expired or the status could not be fetched
+pub async fn wait_for_replicas(
+    client: &NepheliosClient,
+    app_name: &str,
+    timeout: Duration,
+) -> Result<AppStatus> {
+    let started = Instant::now();
+    let bar = create_progress_bar(0, &format!("Waiting for {} tasks", app_name));
+
+    loop {
+        let status = match client.app_status(app_name).await {
+            Ok(status) => status,
+            Err(e) => {
+                bar.finish_and_clear();
+                return Err(e);
+            }
+        };
+
+        bar.set_length(status.replicas as u64);
+        bar.set_position(status.running.min(status.replicas) as u64);
+
+        if status.is_converged() {
+            bar.finish_and_clear();
+            return Ok(status);
+        }
+
+        if started.elapsed() >= timeout {
+            bar.finish_and_clear();
+            bail!(
+                "Timed out after {}s: {}/{} tasks of {} are running",
+                timeout.as_secs(),
+                status.running,
+                status.replicas,
+                app_name
+            );
+        }
+
+        tokio::time::sleep(POLL_INTERVAL).await;
+    }
+}
+
+/// Waits until the Nephelios container is healthy and its API answers.
+///
+/// # Arguments
+///
+/// * `service` - The Nephelios container
+/// * `client` - The client used to reach the API
+/// * `timeout` - How long to wait before giving up
+///
+/// # Returns
+///
+/// * `Ok(())` once the API answers
+/// * `Err(anyhow::Error)` if the timeout expired or the container could not be inspected
+pub async fn wait_for_platform(
+    service: &NepheliosService,
+    client: &NepheliosClient,
+    timeout: Duration,
+) -> Result<()> {
+    let started = Instant::now();
+    let spinner = create_spinner("Waiting for the Nephelios container to be healthy...");
+
+    loop {
+        let healthy = match service.is_healthy().await {
+            Ok(healthy) => healthy,
+            Err(e) => {
+                spinner.finish_and_clear();
+                return Err(e);
+            }
+        };
+
+        if healthy {
+            spinner.set_message("Waiting for the Nephelios API...");
+            if client.is_reachable().await {
+                spinner.finish_and_clear();
+                return Ok(());
+            }
+        }
+
+        if started.elapsed() >= timeout {
+            spinner.finish_and_clear();
+            bail!(
+                "Timed out after {}s: Nephelios is {}",
+                timeout.as_secs(),
+                if healthy {
+                    "running but its API does not answer"
+                } else {
+                    "not healthy"
+                }
+            );
+        }
+
+        tokio::time::sleep(POLL_INTERVAL).await;
+    }
+}